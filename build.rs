@@ -2,6 +2,11 @@ fn main() {
     let ac = autocfg::new();
 
     ac.emit_expression_cfg("1f64.total_cmp(&2f64)", "has_total_cmp"); // 1.62
+    ac.emit_expression_cfg(
+        "core::array::from_fn::<u8, 1, _>(|i| i as u8)",
+        "has_array_from_fn",
+    ); // 1.63
+    ac.emit_expression_cfg("core::num::Saturating(0u8)", "has_num_saturating"); // 1.74
 
     autocfg::rerun_path("build.rs");
 }
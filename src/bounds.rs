@@ -3,6 +3,8 @@ use core::{f32, f64};
 use core::{i128, i16, i32, i64, i8, isize};
 use core::{u128, u16, u32, u64, u8, usize};
 
+use crate::ToPrimitive;
+
 /// Numbers which have upper and lower bounds
 pub trait Bounded {
     // FIXME (#5527): These should be associated constants
@@ -12,6 +14,25 @@ pub trait Bounded {
     fn max_value() -> Self;
 }
 
+/// Defines associated constants for the bounds of `Self`, usable in `const`
+/// contexts.
+///
+/// # Examples
+///
+/// ```
+/// use core::num::Wrapping;
+/// use num_traits::ConstBounded;
+///
+/// const MAX: Wrapping<i32> = <Wrapping<i32> as ConstBounded>::MAX;
+/// assert_eq!(MAX, Wrapping(i32::MAX));
+/// ```
+pub trait ConstBounded: Bounded {
+    /// The smallest finite number this type can represent.
+    const MIN: Self;
+    /// The largest finite number this type can represent.
+    const MAX: Self;
+}
+
 /// Numbers which have lower bounds
 pub trait LowerBounded {
     /// Returns the smallest finite number this type can represent
@@ -51,6 +72,11 @@ macro_rules! bounded_impl {
                 $max
             }
         }
+
+        impl ConstBounded for $t {
+            const MIN: Self = $min;
+            const MAX: Self = $max;
+        }
     };
 }
 
@@ -68,6 +94,8 @@ bounded_impl!(i32, i32::MIN, i32::MAX);
 bounded_impl!(i64, i64::MIN, i64::MAX);
 bounded_impl!(i128, i128::MIN, i128::MAX);
 
+bounded_impl!(char, '\0', char::MAX);
+
 impl<T: Bounded> Bounded for Wrapping<T> {
     fn min_value() -> Self {
         Wrapping(T::min_value())
@@ -77,6 +105,11 @@ impl<T: Bounded> Bounded for Wrapping<T> {
     }
 }
 
+impl<T: ConstBounded> ConstBounded for Wrapping<T> {
+    const MIN: Self = Wrapping(T::MIN);
+    const MAX: Self = Wrapping(T::MAX);
+}
+
 bounded_impl!(f32, f32::MIN, f32::MAX);
 
 macro_rules! for_each_tuple_ {
@@ -106,12 +139,75 @@ macro_rules! bounded_tuple {
                 ($($name::max_value(),)*)
             }
         }
+
+        impl<$($name: ConstBounded,)*> ConstBounded for ($($name,)*) {
+            const MIN: Self = ($($name::MIN,)*);
+            const MAX: Self = ($($name::MAX,)*);
+        }
     );
 }
 
 for_each_tuple!(bounded_tuple);
 bounded_impl!(f64, f64::MIN, f64::MAX);
 
+#[cfg(has_array_from_fn)]
+#[allow(clippy::incompatible_msrv)]
+impl<T: Bounded, const N: usize> Bounded for [T; N] {
+    #[inline]
+    fn min_value() -> Self {
+        core::array::from_fn(|_| T::min_value())
+    }
+    #[inline]
+    fn max_value() -> Self {
+        core::array::from_fn(|_| T::max_value())
+    }
+}
+
+// Without `array::from_fn`, arrays of `Copy` bounds can still be repeated,
+// which also keeps `Bounded` available for the `ConstBounded` impl below.
+#[cfg(not(has_array_from_fn))]
+impl<T: Bounded + Copy, const N: usize> Bounded for [T; N] {
+    #[inline]
+    fn min_value() -> Self {
+        [T::min_value(); N]
+    }
+    #[inline]
+    fn max_value() -> Self {
+        [T::max_value(); N]
+    }
+}
+
+impl<T: ConstBounded + Copy, const N: usize> ConstBounded for [T; N] {
+    const MIN: Self = [T::MIN; N];
+    const MAX: Self = [T::MAX; N];
+}
+
+/// Returns the number of distinct values of the integer type `T`, that is
+/// `MAX - MIN + 1`, as a `u128`.
+///
+/// This is useful for sampling uniformly from the whole range of a type. The
+/// result is `None` if the range does not fit in a `u128`, as for `u128` and
+/// `i128` themselves, or if the bounds of `T` are not integers.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::bounds::range_size;
+///
+/// assert_eq!(range_size::<u8>(), Some(256));
+/// assert_eq!(range_size::<i16>(), Some(65536));
+/// assert_eq!(range_size::<u64>(), Some(1 << 64));
+/// assert_eq!(range_size::<i128>(), None);
+/// ```
+pub fn range_size<T: Bounded + ToPrimitive>() -> Option<u128> {
+    let (min, max) = (T::min_value(), T::max_value());
+    let span = match min.to_u128() {
+        Some(min) => max.to_u128()?.checked_sub(min)?,
+        None => max.to_i128()?.wrapping_sub(min.to_i128()?) as u128,
+    };
+    span.checked_add(1)
+}
+
 #[test]
 fn wrapping_bounded() {
     macro_rules! test_wrapping_bounded {
@@ -146,3 +242,80 @@ fn wrapping_is_bounded() {
     require_bounded(&Wrapping(42_u32));
     require_bounded(&Wrapping(-42));
 }
+
+#[test]
+fn const_bounded() {
+    const MIN: Wrapping<i32> = <Wrapping<i32> as ConstBounded>::MIN;
+    const MAX: Wrapping<i32> = <Wrapping<i32> as ConstBounded>::MAX;
+    assert_eq!(MIN, Wrapping(i32::MIN));
+    assert_eq!(MAX, Wrapping(i32::MAX));
+
+    macro_rules! test_const_bounded {
+        ($($t:ty)+) => {
+            $(
+                assert_eq!(<$t as ConstBounded>::MIN, <$t as Bounded>::min_value());
+                assert_eq!(<$t as ConstBounded>::MAX, <$t as Bounded>::max_value());
+                assert_eq!(<Wrapping<$t> as ConstBounded>::MIN.0, <$t>::min_value());
+                assert_eq!(<Wrapping<$t> as ConstBounded>::MAX.0, <$t>::max_value());
+            )+
+        };
+    }
+
+    test_const_bounded!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+    assert_eq!(<f32 as ConstBounded>::MIN, f32::MIN);
+    assert_eq!(<f64 as ConstBounded>::MAX, f64::MAX);
+
+    const PAIR: (u8, Wrapping<i16>) = <(u8, Wrapping<i16>) as ConstBounded>::MAX;
+    assert_eq!(PAIR, (u8::MAX, Wrapping(i16::MAX)));
+    assert_eq!(<() as ConstBounded>::MIN, ());
+
+    const ARRAY: [i16; 3] = <[i16; 3] as ConstBounded>::MIN;
+    assert_eq!(ARRAY, [i16::MIN; 3]);
+    assert_eq!(<[i16; 3] as ConstBounded>::MAX, [i16::MAX; 3]);
+    assert_eq!(<[u8; 2] as Bounded>::max_value(), [u8::MAX; 2]);
+}
+
+#[test]
+fn char_bounded() {
+    assert_eq!(<char as Bounded>::min_value(), '\0');
+    assert_eq!(<char as Bounded>::max_value(), '\u{10ffff}');
+    assert_eq!(<char as LowerBounded>::min_value(), '\0');
+    assert_eq!(<char as UpperBounded>::max_value(), char::MAX);
+    assert_eq!(<char as ConstBounded>::MIN, '\0');
+    assert_eq!(<char as ConstBounded>::MAX, char::MAX);
+    assert_eq!(<(char, u8) as Bounded>::max_value(), (char::MAX, u8::MAX));
+}
+
+#[test]
+#[cfg(has_array_from_fn)]
+fn array_bounded() {
+    let min = <[i16; 3] as Bounded>::min_value();
+    let max = <[i16; 3] as Bounded>::max_value();
+    assert!(min.iter().all(|&x| x == i16::MIN));
+    assert!(max.iter().all(|&x| x == i16::MAX));
+    // Arrays compare lexicographically, so these are the extreme keys.
+    let key = [3u8, 250, 0, 17];
+    assert!(<[u8; 4] as Bounded>::min_value() <= key);
+    assert!(key <= <[u8; 4] as Bounded>::max_value());
+
+    assert_eq!(
+        <[Wrapping<u8>; 2] as Bounded>::max_value(),
+        [Wrapping(255); 2]
+    );
+    assert_eq!(<[(u8, i8); 1] as Bounded>::min_value(), [(0, -128)]);
+    assert_eq!(<[u64; 0] as Bounded>::max_value(), []);
+}
+
+#[test]
+fn range_size_of_integers() {
+    assert_eq!(range_size::<i8>(), Some(256));
+    assert_eq!(range_size::<u8>(), Some(256));
+    assert_eq!(range_size::<i32>(), Some(1 << 32));
+    assert_eq!(range_size::<u32>(), Some(1 << 32));
+    assert_eq!(range_size::<i64>(), Some(1 << 64));
+    assert_eq!(range_size::<u64>(), Some(1 << 64));
+    assert_eq!(range_size::<usize>(), Some(usize::max_value() as u128 + 1));
+    assert_eq!(range_size::<Wrapping<i16>>(), Some(65536));
+    assert_eq!(range_size::<i128>(), None);
+    assert_eq!(range_size::<u128>(), None);
+}
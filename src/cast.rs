@@ -1,9 +1,14 @@
+use core::fmt;
 use core::mem::size_of;
 use core::num::Wrapping;
 use core::{f32, f64};
 use core::{i128, i16, i32, i64, i8, isize};
 use core::{u128, u16, u32, u64, u8, usize};
 
+use crate::bounds::Bounded;
+use crate::float::FloatCore;
+use crate::identities::Zero;
+
 /// A generic trait for converting a value to a number.
 ///
 /// A value can be represented by the target type when it lies within
@@ -123,6 +128,79 @@ pub trait ToPrimitive {
             None => self.to_u64().as_ref().and_then(ToPrimitive::to_f64),
         }
     }
+
+    /// Converts the value of `self` to an `f64`, rounding with the given `mode`
+    /// when it is not exactly representable. `None` is returned if the value
+    /// cannot be represented by an `f64`.
+    ///
+    /// The default implementation starts from `to_f64()`. When that result
+    /// is an integer that agrees with `to_i128()` or `to_u128()`, the exact
+    /// integer is used to correct the rounding. Otherwise the result of
+    /// `to_f64()` is returned as is, so types whose `to_f64()` rounds
+    /// non-integer values should override this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::cast::{RoundMode, ToPrimitive};
+    ///
+    /// let x = (1u64 << 53) + 1;
+    /// assert_eq!(x.to_f64_round(RoundMode::NearestEven), Some(9007199254740992.0));
+    /// assert_eq!(x.to_f64_round(RoundMode::Up), Some(9007199254740994.0));
+    /// ```
+    fn to_f64_round(&self, mode: RoundMode) -> Option<f64> {
+        use core::cmp::Ordering;
+
+        let f = self.to_f64()?;
+        if !f.is_finite() || FloatCore::trunc(f) != f {
+            return Some(f);
+        }
+
+        // `f` is an integer, so an integer conversion that rounds to it is
+        // taken as the exact value, which tells which way `f` was rounded.
+        let ord = if let Some(i) = self.to_i128().filter(|&i| i as f64 == f) {
+            // `i128::MAX as f64` rounds up to 2^127, which is out of range.
+            if f >= i128::MAX as f64 {
+                Ordering::Greater
+            } else {
+                (f as i128).cmp(&i)
+            }
+        } else if let Some(u) = self.to_u128().filter(|&u| u as f64 == f) {
+            // `u128::MAX as f64` rounds up to 2^128, which is out of range.
+            if f >= u128::MAX as f64 {
+                Ordering::Greater
+            } else {
+                (f as u128).cmp(&u)
+            }
+        } else {
+            return Some(f);
+        };
+
+        // `f` is already the nearest value, ties to even, so at most one step
+        // back toward the exact value is needed.
+        Some(match (mode, ord) {
+            (RoundMode::Up, Ordering::Less) => FloatCore::next_up(f),
+            (RoundMode::Down, Ordering::Greater) => FloatCore::next_down(f),
+            (RoundMode::TowardZero, Ordering::Greater) if f > 0.0 => FloatCore::next_down(f),
+            (RoundMode::TowardZero, Ordering::Less) if f < 0.0 => FloatCore::next_up(f),
+            _ => f,
+        })
+    }
+}
+
+/// The rounding mode used by [`ToPrimitive::to_f64_round`] when a value is not
+/// exactly representable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round to the nearest value, with ties going to the value with an even
+    /// least significant digit.
+    NearestEven,
+    /// Round toward zero, i.e. truncate.
+    TowardZero,
+    /// Round toward positive infinity.
+    Up,
+    /// Round toward negative infinity.
+    Down,
 }
 
 macro_rules! impl_to_primitive_int_to_int {
@@ -367,6 +445,12 @@ macro_rules! impl_to_primitive_float {
                 fn to_f32 -> f32;
                 fn to_f64 -> f64;
             }
+
+            #[inline]
+            fn to_f64_round(&self, _mode: RoundMode) -> Option<f64> {
+                // Every `f32` and `f64` is exactly representable as `f64`.
+                Some(*self as f64)
+            }
         }
     };
 }
@@ -489,6 +573,71 @@ pub trait FromPrimitive: Sized {
             None => n.to_u64().and_then(FromPrimitive::from_u64),
         }
     }
+
+    /// Converts a `f64` to return an optional value of this type, only if `n`
+    /// is a finite integer. If `n` has a fractional part, is not finite, or
+    /// cannot be represented by this type, then `None` is returned.
+    ///
+    /// Unlike `from_f64`, this never discards a fractional part or rounds, so
+    /// it only succeeds when the result converts back to exactly `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::FromPrimitive;
+    ///
+    /// assert_eq!(i32::from_f64_exact(3.0), Some(3));
+    /// assert_eq!(i32::from_f64_exact(3.5), None);
+    /// assert_eq!(u8::from_f64_exact(-1.0), None);
+    /// assert_eq!(u8::from_f64_exact(256.0), None);
+    /// assert_eq!(f32::from_f64_exact(16777217.0), None);
+    /// ```
+    #[inline]
+    fn from_f64_exact(n: f64) -> Option<Self>
+    where
+        Self: ToPrimitive,
+    {
+        if n.is_finite() && FloatCore::trunc(n) == n {
+            FromPrimitive::from_f64(n).filter(|value: &Self| value.to_f64() == Some(n))
+        } else {
+            None
+        }
+    }
+
+    /// Converts a `f64` to a value of this type, clamping values that are out
+    /// of range to the type's bounds. NaN is mapped to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::FromPrimitive;
+    ///
+    /// assert_eq!(u8::from_f64_saturating(1e9), 255);
+    /// assert_eq!(u8::from_f64_saturating(-1e9), 0);
+    /// assert_eq!(i8::from_f64_saturating(-12.7), -12);
+    /// assert_eq!(i32::from_f64_saturating(f64::NAN), 0);
+    /// assert_eq!(f32::from_f64_saturating(1e300), f32::MAX);
+    /// ```
+    #[inline]
+    fn from_f64_saturating(n: f64) -> Self
+    where
+        Self: Bounded + Zero + ToPrimitive,
+    {
+        let (min, max) = (Self::min_value(), Self::max_value());
+        if n.is_nan() {
+            Self::zero()
+        } else if max.to_f64().map_or(false, |max| n >= max) {
+            max
+        } else if min.to_f64().map_or(false, |min| n <= min) {
+            min
+        } else if let Some(value) = FromPrimitive::from_f64(n) {
+            value
+        } else if n > 0.0 {
+            max
+        } else {
+            min
+        }
+    }
 }
 
 macro_rules! impl_from_primitive {
@@ -649,6 +798,67 @@ pub fn cast<T: NumCast, U: NumCast>(n: T) -> Option<U> {
     NumCast::from(n)
 }
 
+/// Cast from one machine scalar to another, only if the value is preserved
+/// exactly.
+///
+/// See [`NumCast::cast_exact`].
+///
+/// # Examples
+///
+/// ```
+/// # use num_traits as num;
+/// assert_eq!(num::cast::cast_exact::<u64, f64>(1 << 53), Some(9007199254740992.0));
+/// assert_eq!(num::cast::cast_exact::<u64, f64>((1 << 53) + 1), None);
+/// ```
+///
+#[inline]
+pub fn cast_exact<T: NumCast + Clone + PartialEq, U: NumCast + Clone>(n: T) -> Option<U> {
+    n.cast_exact()
+}
+
+/// Cast from one machine scalar to another, reporting why the conversion
+/// failed.
+///
+/// See [`NumCast::try_cast_from`].
+///
+/// # Examples
+///
+/// ```
+/// # use num_traits as num;
+/// use num::cast::CastError;
+///
+/// assert_eq!(num::cast::try_cast::<f64, i32>(42.9), Ok(42));
+/// assert_eq!(num::cast::try_cast::<f64, i32>(1e10), Err(CastError::Overflow));
+/// ```
+///
+#[inline]
+pub fn try_cast<T: NumCast, U: NumCast>(n: T) -> Result<U, CastError> {
+    U::try_cast_from(n)
+}
+
+/// The reason a value could not be converted by [`try_cast`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CastError {
+    /// The value is greater than the largest value of the target type.
+    Overflow,
+    /// The value is less than the smallest value of the target type.
+    Underflow,
+    /// The value is NaN or infinite, and the target type has no such values.
+    NotFinite,
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match *self {
+            CastError::Overflow => "value is too large for the target type",
+            CastError::Underflow => "value is too small for the target type",
+            CastError::NotFinite => "value is not finite",
+        };
+
+        description.fmt(f)
+    }
+}
+
 /// An interface for casting between machine scalars.
 pub trait NumCast: Sized + ToPrimitive {
     /// Creates a number from another value that can be converted into
@@ -664,6 +874,82 @@ pub trait NumCast: Sized + ToPrimitive {
     /// are admitted, like an `f32` with a decimal part to an integer type, or
     /// even a large `f64` saturating to `f32` infinity.
     fn from<T: ToPrimitive>(n: T) -> Option<Self>;
+
+    /// Converts `self` to another number type, only if the value is preserved
+    /// exactly. `None` is returned if the conversion is out of range, or if it
+    /// would round or truncate the value.
+    ///
+    /// This checks that converting the result back gives the original value,
+    /// so NaN, which is not equal to itself, never converts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::NumCast;
+    ///
+    /// assert_eq!(3u64.cast_exact::<f32>(), Some(3.0));
+    /// assert_eq!(((1u64 << 40) + 1).cast_exact::<f32>(), None);
+    /// assert_eq!(2.5f64.cast_exact::<i32>(), None);
+    /// assert_eq!(300i32.cast_exact::<u8>(), None);
+    /// ```
+    #[inline]
+    fn cast_exact<T: NumCast + Clone>(self) -> Option<T>
+    where
+        Self: Clone + PartialEq,
+    {
+        let value = T::from(self.clone())?;
+        if <Self as NumCast>::from(value.clone())? == self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self` can be converted to `T` without changing its
+    /// value, that is if [`cast_exact`](NumCast::cast_exact) would succeed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::NumCast;
+    ///
+    /// assert!(1000u64.cast_is_lossless::<f32>());
+    /// assert!(!u64::MAX.cast_is_lossless::<f32>());
+    /// assert!(!(-1i8).cast_is_lossless::<u8>());
+    /// ```
+    #[inline]
+    fn cast_is_lossless<T: NumCast + Clone>(&self) -> bool
+    where
+        Self: Clone + PartialEq,
+    {
+        self.clone().cast_exact::<T>().is_some()
+    }
+
+    /// Creates a number from another value like [`NumCast::from`], but
+    /// reports why the conversion failed.
+    ///
+    /// The default implementation classifies a failed conversion by the value
+    /// of `n` as an `f64`: NaN and infinities are `NotFinite`, other negative
+    /// values are `Underflow`, and everything else is `Overflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::cast::{CastError, NumCast};
+    ///
+    /// assert_eq!(u8::try_cast_from(255.5f64), Ok(255));
+    /// assert_eq!(u8::try_cast_from(-1.0f64), Err(CastError::Underflow));
+    /// assert_eq!(u8::try_cast_from(f64::NAN), Err(CastError::NotFinite));
+    /// ```
+    #[inline]
+    fn try_cast_from<T: ToPrimitive>(n: T) -> Result<Self, CastError> {
+        let value = n.to_f64();
+        <Self as NumCast>::from(n).ok_or(match value {
+            Some(v) if v.is_nan() || v.is_infinite() => CastError::NotFinite,
+            Some(v) if v < 0.0 => CastError::Underflow,
+            _ => CastError::Overflow,
+        })
+    }
 }
 
 macro_rules! impl_num_cast {
@@ -768,3 +1054,62 @@ impl_as_primitive!(f32 => { f32, f64 });
 impl_as_primitive!(f64 => { f32, f64 });
 impl_as_primitive!(char => { char });
 impl_as_primitive!(bool => {});
+
+// `NonZero*` types convert like the primitive they wrap.
+macro_rules! impl_as_primitive_nonzero {
+    ($($NZ:ident => $T:ty),*) => {$(
+        impl<U> AsPrimitive<U> for core::num::$NZ
+        where
+            U: 'static + Copy,
+            $T: AsPrimitive<U>,
+        {
+            #[inline]
+            fn as_(self) -> U {
+                self.get().as_()
+            }
+        }
+    )*};
+}
+
+impl_as_primitive_nonzero!(NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32);
+impl_as_primitive_nonzero!(NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize);
+impl_as_primitive_nonzero!(NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32);
+impl_as_primitive_nonzero!(NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize);
+
+/// Checked conversions between the `NonZero*` integer types.
+///
+/// The converted value is nonzero whenever it fits in the destination type, so
+/// the only failure is a value that is out of range.
+pub trait TryIntoNonZero: Sized + Copy {
+    /// Converts `self` to another `NonZero*` type, returning `None` if the value
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::{NonZeroI8, NonZeroU32, NonZeroU8};
+    /// use num_traits::cast::TryIntoNonZero;
+    ///
+    /// let x = NonZeroU32::new(300).unwrap();
+    /// assert_eq!(x.try_into_nonzero::<NonZeroU8>(), None);
+    ///
+    /// let y = NonZeroU32::new(100).unwrap();
+    /// assert_eq!(y.try_into_nonzero::<NonZeroI8>(), NonZeroI8::new(100));
+    /// ```
+    #[inline]
+    fn try_into_nonzero<Dst>(self) -> Option<Dst>
+    where
+        Dst: TryIntoNonZero + TryFrom<Self>,
+    {
+        Dst::try_from(self).ok()
+    }
+}
+
+macro_rules! impl_try_into_nonzero {
+    ($($T:ident)*) => {$(
+        impl TryIntoNonZero for core::num::$T {}
+    )*};
+}
+
+impl_try_into_nonzero!(NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize);
+impl_try_into_nonzero!(NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128 NonZeroIsize);
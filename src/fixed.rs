@@ -0,0 +1,255 @@
+//! Conversions between floating point and binary fixed-point numbers.
+//!
+//! A fixed-point number in Q format with `frac_bits` fractional bits stores a
+//! value `x` as the integer `x * 2^frac_bits`. For example, `0.5` in Q8 is
+//! stored as `128`.
+
+use crate::float::FloatCore;
+use crate::{Bounded, NumCast, PrimInt};
+
+/// Converts `value` to a fixed-point integer with `frac_bits` fractional bits,
+/// rounding to the nearest representable value, with ties away from zero.
+///
+/// Returns `None` if `value` is NaN or the result does not fit in `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::fixed::to_fixed;
+///
+/// assert_eq!(to_fixed::<i16>(0.5, 8), Some(128));
+/// assert_eq!(to_fixed::<i16>(-1.25, 8), Some(-320));
+/// assert_eq!(to_fixed::<i16>(128.0, 8), None);
+/// assert_eq!(to_fixed::<u8>(-0.5, 4), None);
+/// ```
+pub fn to_fixed<T: PrimInt>(value: f64, frac_bits: u32) -> Option<T> {
+    let scaled = FloatCore::ldexp(value, exponent(frac_bits));
+    <T as NumCast>::from(FloatCore::round(scaled))
+}
+
+/// Converts a fixed-point integer with `frac_bits` fractional bits to `f64`.
+///
+/// The result is exact unless `raw` has more significant bits than an `f64`
+/// mantissa, or the value is out of the range of `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::fixed::from_fixed;
+///
+/// assert_eq!(from_fixed(128i16, 8), 0.5);
+/// assert_eq!(from_fixed(-320i16, 8), -1.25);
+/// assert_eq!(from_fixed(3u8, 0), 3.0);
+/// ```
+pub fn from_fixed<T: PrimInt>(raw: T, frac_bits: u32) -> f64 {
+    let value = raw.to_f64().unwrap();
+    FloatCore::ldexp(value, exponent(frac_bits).wrapping_neg())
+}
+
+/// Converts a fixed-point integer from `from_frac` to `to_frac` fractional
+/// bits.
+///
+/// Adding fractional bits is exact, except that high bits shifted out of `T`
+/// are lost. Removing fractional bits rounds to the nearest representable
+/// value, with ties away from zero as in [`to_fixed`].
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::fixed::requantize;
+///
+/// // 1.5 from Q4 to Q8 and back.
+/// assert_eq!(requantize(24i16, 4, 8), 384);
+/// assert_eq!(requantize(384i16, 8, 4), 24);
+///
+/// // 0x0.18 is 0.09375, which lies between 0x0.1 and 0x0.2 in Q4.
+/// assert_eq!(requantize(0x18u8, 8, 4), 0x2);
+/// assert_eq!(requantize(-0x18i16, 8, 4), -0x2);
+/// ```
+pub fn requantize<T: PrimInt>(raw: T, from_frac: u32, to_frac: u32) -> T {
+    let bits = T::zero().count_zeros();
+    if to_frac >= from_frac {
+        let shift = to_frac - from_frac;
+        return if shift < bits {
+            raw << shift as usize
+        } else {
+            T::zero()
+        };
+    }
+
+    let shift = from_frac - to_frac;
+    if shift > bits {
+        return T::zero();
+    }
+    // Shift in two steps so that the last bit shifted out, worth one half in
+    // the result, is kept apart from the bits below it.
+    let halves = raw >> (shift - 1) as usize;
+    let quotient = halves >> 1;
+    let below_half = raw & !(!T::zero() << (shift - 1) as usize);
+    let is_negative = raw < T::zero();
+    let round_up = !(halves & T::one()).is_zero() && (!below_half.is_zero() || !is_negative);
+    // The quotient of an arithmetic shift is the floor, which already rounds
+    // ties away from zero for negative values.
+    if round_up {
+        quotient + T::one()
+    } else {
+        quotient
+    }
+}
+
+/// Maps `value` linearly from the range `in_min..=in_max` onto the whole range
+/// of `T`, rounding to the nearest value and clamping at the ends.
+///
+/// This suits a sensor reading converted for a DAC or another integer output.
+/// Values at or below `in_min` map to `T::min_value()`, and values at or above
+/// `in_max` map to `T::max_value()`, as does NaN to the former. The range
+/// should have `in_min < in_max`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::fixed::scale_clamp_to;
+///
+/// assert_eq!(scale_clamp_to::<u8>(1.65, 0.0, 3.3), 128);
+/// assert_eq!(scale_clamp_to::<u8>(5.0, 0.0, 3.3), 255);
+/// assert_eq!(scale_clamp_to::<i8>(1.0, -1.0, 1.0), 127);
+/// assert_eq!(scale_clamp_to::<i8>(-2.0, -1.0, 1.0), -128);
+/// ```
+pub fn scale_clamp_to<T: Bounded + NumCast>(value: f64, in_min: f64, in_max: f64) -> T {
+    if value.is_nan() || value <= in_min {
+        return T::min_value();
+    } else if value >= in_max {
+        return T::max_value();
+    }
+
+    let t = (value - in_min) / (in_max - in_min);
+
+    let lo = T::min_value().to_f64().unwrap();
+    let hi = T::max_value().to_f64().unwrap();
+    let scaled = FloatCore::round(lo + t * (hi - lo));
+    // This stays in range for the primitive types, but other bounds may
+    // not convert exactly to `f64`.
+    <T as NumCast>::from(scaled).unwrap_or_else(T::max_value)
+}
+
+/// Converts a number of fractional bits to a scaling exponent, saturating at
+/// a value that is already out of range for any `f64`.
+fn exponent(frac_bits: u32) -> i32 {
+    if frac_bits > 4096 {
+        4096
+    } else {
+        frac_bits as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(to_fixed::<i16>(0.5, 8), Some(128i16));
+        assert_eq!(from_fixed(128i16, 8), 0.5);
+
+        for &(x, bits) in &[
+            (0.0, 8),
+            (-0.75, 8),
+            (1.0 / 3.0, 30),
+            (100.125, 3),
+            (-2.0, 62),
+        ] {
+            let raw = to_fixed::<i64>(x, bits).unwrap();
+            let back = from_fixed(raw, bits);
+            assert!((back - x).abs() <= FloatCore::ldexp(0.5, -(bits as i32)));
+        }
+    }
+
+    #[test]
+    fn rounding_and_range() {
+        assert_eq!(to_fixed::<i8>(0.1, 4), Some(2));
+        assert_eq!(to_fixed::<i8>(-0.1, 4), Some(-2));
+        assert_eq!(to_fixed::<i8>(0.03125, 4), Some(1));
+        assert_eq!(to_fixed::<i8>(-0.03125, 4), Some(-1));
+        assert_eq!(to_fixed::<i8>(7.96875, 4), None);
+        assert_eq!(to_fixed::<i8>(-8.0, 4), Some(i8::MIN));
+        assert_eq!(to_fixed::<u8>(15.9, 4), Some(254));
+        assert_eq!(to_fixed::<u32>(1.0, 32), None);
+        assert_eq!(to_fixed::<u64>(1.0, 32), Some(1 << 32));
+        assert_eq!(to_fixed::<i32>(f64::NAN, 4), None);
+        assert_eq!(to_fixed::<i32>(f64::INFINITY, 0), None);
+        assert_eq!(to_fixed::<i32>(1.0, u32::MAX), None);
+        assert_eq!(to_fixed::<i32>(1e-300, u32::MAX), None);
+
+        assert_eq!(from_fixed(i8::MIN, 7), -1.0);
+        assert_eq!(from_fixed(u128::MAX, 128), 1.0);
+        assert_eq!(from_fixed(1u8, u32::MAX), 0.0);
+    }
+
+    #[test]
+    fn scale_clamp() {
+        // A 12-bit DAC fed from the top bits of a `u16`.
+        let dac = |volts: f64| scale_clamp_to::<u16>(volts, 0.0, 3.3) >> 4;
+        assert_eq!(dac(-0.1), 0);
+        assert_eq!(dac(0.0), 0);
+        assert_eq!(dac(1.65), 0x800);
+        assert_eq!(dac(3.3), 0xfff);
+        assert_eq!(dac(4.0), 0xfff);
+        assert_eq!(dac(f64::NAN), 0);
+        assert_eq!(dac(f64::INFINITY), 0xfff);
+
+        assert_eq!(scale_clamp_to::<u8>(0.0, 0.0, 255.0), 0);
+        assert_eq!(scale_clamp_to::<u8>(17.4, 0.0, 255.0), 17);
+        assert_eq!(scale_clamp_to::<u8>(17.5, 0.0, 255.0), 18);
+        // The midpoint of the `i16` range is -0.5, which rounds away from zero.
+        assert_eq!(scale_clamp_to::<i16>(0.5, 0.0, 1.0), -1);
+        assert_eq!(scale_clamp_to::<i16>(0.5 + 1e-5, 0.0, 1.0), 0);
+        assert_eq!(scale_clamp_to::<i16>(1.0, 0.0, 1.0), i16::MAX);
+        assert_eq!(
+            scale_clamp_to::<u64>(1.0 - f64::EPSILON / 2.0, 0.0, 1.0),
+            u64::MAX - 2047
+        );
+        assert_eq!(scale_clamp_to::<i128>(-1e300, -1.0, 1.0), i128::MIN);
+        assert_eq!(scale_clamp_to::<f32>(0.5, 0.0, 1.0), 0.0);
+        assert_eq!(scale_clamp_to::<u8>(3.0, 3.0, 3.0), 0);
+        assert_eq!(scale_clamp_to::<u8>(3.5, 3.0, 3.0), 255);
+    }
+
+    #[test]
+    fn requantize_q8_q4() {
+        // Q4 to Q8 is exact.
+        for raw in i8::MIN..=i8::MAX {
+            let wide = requantize(raw as i16, 4, 8);
+            assert_eq!(wide, (raw as i16) * 16);
+            assert_eq!(from_fixed(wide, 8), from_fixed(raw, 4));
+            assert_eq!(requantize(wide, 8, 4), raw as i16);
+        }
+
+        // Q8 to Q4 rounds to nearest, ties away from zero.
+        assert_eq!(requantize(0x17i16, 8, 4), 0x1);
+        assert_eq!(requantize(0x18i16, 8, 4), 0x2);
+        assert_eq!(requantize(0x19i16, 8, 4), 0x2);
+        assert_eq!(requantize(-0x17i16, 8, 4), -0x1);
+        assert_eq!(requantize(-0x18i16, 8, 4), -0x2);
+        assert_eq!(requantize(-0x19i16, 8, 4), -0x2);
+        assert_eq!(requantize(0x07u16, 8, 4), 0x0);
+        assert_eq!(requantize(0x08u16, 8, 4), 0x1);
+        for raw in i16::MIN..=i16::MAX {
+            let expected = to_fixed::<i16>(from_fixed(raw, 8), 4).unwrap();
+            assert_eq!(requantize(raw, 8, 4), expected);
+        }
+        for raw in 0..=u16::MAX {
+            let expected = to_fixed::<u16>(from_fixed(raw, 8), 4).unwrap();
+            assert_eq!(requantize(raw, 8, 4), expected);
+        }
+
+        // Shifts by the full width and beyond.
+        assert_eq!(requantize(200u8, 8, 0), 1);
+        assert_eq!(requantize(100u8, 8, 0), 0);
+        assert_eq!(requantize(i8::MIN, 8, 0), -1);
+        assert_eq!(requantize(i8::MAX, 8, 0), 0);
+        assert_eq!(requantize(u8::MAX, 9, 0), 0);
+        assert_eq!(requantize(1u8, 0, 7), 128);
+        assert_eq!(requantize(1u8, 0, 8), 0);
+        assert_eq!(requantize(-1i32, 3, 3), -1);
+    }
+}
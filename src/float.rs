@@ -1,4 +1,5 @@
 use core::cmp::Ordering;
+use core::fmt;
 use core::num::FpCategory;
 use core::ops::{Add, Div, Neg};
 
@@ -796,6 +797,499 @@ pub trait FloatCore: Num + NumCast + Neg<Output = Self> + PartialOrd + Copy {
     /// check(f64::NEG_INFINITY, 1 << 52, 972, -1);
     /// ```
     fn integer_decode(self) -> (u64, i16, i8);
+
+    /// Returns the least number greater than `self`.
+    ///
+    /// - `NaN` and `INFINITY` are returned unchanged.
+    /// - `NEG_INFINITY` returns the most negative finite value.
+    /// - Zero of either sign returns the smallest positive subnormal value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    ///
+    /// fn check<T: FloatCore>(x: T, up: T) {
+    ///     assert!(x.next_up() == up);
+    /// }
+    ///
+    /// check(1.0f32, 1.0 + f32::EPSILON);
+    /// check(-1.0f64, -1.0 + f64::EPSILON / 2.0);
+    /// check(f64::MAX, f64::INFINITY);
+    /// check(f64::NEG_INFINITY, f64::MIN);
+    /// ```
+    fn next_up(self) -> Self {
+        if self.is_nan() || self == Self::infinity() {
+            return self;
+        }
+        if self == Self::neg_infinity() {
+            return Self::min_value();
+        }
+        if self.is_zero() {
+            return min_subnormal();
+        }
+
+        let (mantissa, _, _) = self.integer_decode();
+        // `abs / mantissa` is exactly the value of one unit in the last place,
+        // except for subnormals where it rounds below the smallest subnormal.
+        let unit = self.abs() / Self::from(mantissa).unwrap();
+        let unit = unit.max(min_subnormal());
+        if self > Self::zero() {
+            self + unit
+        } else {
+            // Moving toward zero from a power of two crosses into the next
+            // lower binade, where the spacing is halved.
+            let step = if mantissa & (mantissa - 1) == 0 {
+                (unit / (Self::one() + Self::one())).max(min_subnormal())
+            } else {
+                unit
+            };
+            let next = self + step;
+            if next.is_zero() {
+                Self::neg_zero()
+            } else {
+                next
+            }
+        }
+    }
+
+    /// Returns the greatest number less than `self`.
+    ///
+    /// - `NaN` and `NEG_INFINITY` are returned unchanged.
+    /// - `INFINITY` returns the largest finite value.
+    /// - Zero of either sign returns the smallest negative subnormal value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    ///
+    /// fn check<T: FloatCore>(x: T, down: T) {
+    ///     assert!(x.next_down() == down);
+    /// }
+    ///
+    /// check(1.0f32, 1.0 - f32::EPSILON / 2.0);
+    /// check(-1.0f64, -1.0 - f64::EPSILON);
+    /// check(f64::MIN, f64::NEG_INFINITY);
+    /// check(f64::INFINITY, f64::MAX);
+    /// ```
+    #[inline]
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    /// Returns the number of steps of [`next_up`] from the smaller of `self`
+    /// and `other` to the larger, that is, their distance in units in the
+    /// last place.
+    ///
+    /// Returns `None` if either value is NaN, or if the values have opposite
+    /// signs. Zero of either sign counts as having the sign of the other
+    /// value, so the distance from `0.0` to the smallest negative subnormal is
+    /// 1. Infinities are one step beyond the largest finite values.
+    ///
+    /// [`next_up`]: FloatCore::next_up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    ///
+    /// assert_eq!(FloatCore::ulps_between(1.0f32, 1.0 + f32::EPSILON), Some(1));
+    /// assert_eq!(FloatCore::ulps_between(2.0f64, 1.0), Some(1 << 52));
+    /// assert_eq!(FloatCore::ulps_between(-1.0f64, 1.0), None);
+    /// assert_eq!(FloatCore::ulps_between(f64::NAN, 1.0), None);
+    /// ```
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let opposite = self.is_sign_negative() != other.is_sign_negative();
+        if opposite && !self.is_zero() && !other.is_zero() {
+            return None;
+        }
+        Some(ulp_ordinal(self).abs_diff(ulp_ordinal(other)))
+    }
+
+    /// Breaks the number into a normalized fraction and a base-2 exponent,
+    /// such that `self = fraction * 2^exponent`.
+    ///
+    /// The fraction has the sign of `self` and a magnitude in `[0.5, 1)`.
+    /// Zero, infinity and NaN are returned unchanged with an exponent of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    ///
+    /// assert_eq!(FloatCore::frexp(8.0f32), (0.5, 4));
+    /// assert_eq!(FloatCore::frexp(-0.375f64), (-0.75, -1));
+    /// assert_eq!(FloatCore::frexp(0.0f64), (0.0, 0));
+    /// ```
+    fn frexp(self) -> (Self, i32) {
+        if self.is_zero() || !self.is_finite() {
+            return (self, 0);
+        }
+        let (mantissa, exponent, sign) = self.integer_decode();
+        let bits = 64 - mantissa.leading_zeros() as i32;
+        // The mantissa always fits in `Self` exactly, and so does the scaled
+        // fraction, since it is a normal number.
+        let fraction = Self::from(mantissa).unwrap() * pow2(-bits);
+        let fraction = if sign < 0 { -fraction } else { fraction };
+        (fraction, exponent as i32 + bits)
+    }
+
+    /// Multiplies the number by `2^exp`, rounding only once if the result is
+    /// subnormal.
+    ///
+    /// Overflow gives an infinity, and underflow gives a zero, with the sign
+    /// of `self`. Zero, infinity and NaN are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    ///
+    /// assert_eq!(FloatCore::ldexp(0.75f32, 3), 6.0);
+    /// assert_eq!(FloatCore::ldexp(1.0f64, -1074), 5e-324);
+    /// assert_eq!(FloatCore::ldexp(1.0f64, 1024), f64::INFINITY);
+    /// ```
+    fn ldexp(self, exp: i32) -> Self {
+        if self.is_zero() || !self.is_finite() || exp == 0 {
+            return self;
+        }
+        let (emin, emax) = exponent_range::<Self>();
+        let (fraction, e) = self.frexp();
+        let e = e.saturating_add(exp);
+        if e > emax + 1 {
+            // Out of range even for the largest fraction.
+            self * Self::infinity()
+        } else if e > emax {
+            // `2^e` itself would overflow, so scale in two exact steps.
+            fraction * pow2(emax) * pow2(e - emax)
+        } else if e > emin {
+            // The result is normal, so this is exact.
+            fraction * pow2(e)
+        } else if e - emin > emin {
+            // The result is subnormal: scale down exactly while staying
+            // normal, then round once in the final step.
+            fraction * pow2(e - emin) * pow2(emin)
+        } else {
+            // Far below half of the smallest subnormal.
+            self * Self::zero()
+        }
+    }
+
+    /// Returns the fraction `p / q` closest to `self` with a denominator of
+    /// at most `max_denom`, as the pair `(p, q)`.
+    ///
+    /// This uses the continued fraction expansion of `self`, including
+    /// semiconvergents, so the result is the best rational approximation
+    /// within the bound. Values too large for an `i64` numerator saturate to
+    /// `(±i64::MAX, 1)`, infinities give `(±1, 0)` and NaN gives `(0, 0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denom` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::FloatCore;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(FloatCore::to_rational_approx(0.5f64, 100), (1, 2));
+    /// assert_eq!(FloatCore::to_rational_approx(PI, 10), (22, 7));
+    /// assert_eq!(FloatCore::to_rational_approx(PI, 1000), (355, 113));
+    /// assert_eq!(FloatCore::to_rational_approx(-1.25f32, 3), (-4, 3));
+    /// ```
+    fn to_rational_approx(self, max_denom: u64) -> (i64, u64) {
+        assert!(max_denom > 0, "max_denom must be positive");
+        if self.is_nan() {
+            return (0, 0);
+        }
+        let sign = if self.is_sign_negative() { -1 } else { 1 };
+        if self.is_infinite() {
+            return (sign, 0);
+        }
+        let x = self.abs();
+        if x.to_i64().is_none() {
+            return (sign * i64::MAX, 1);
+        }
+
+        // Expand the exact value `numer / denom` of `x`. The denominator is
+        // capped at 2^127, which only drops bits far below `1 / max_denom`.
+        let (mantissa, exponent, _) = x.integer_decode();
+        if exponent >= 0 {
+            return (sign * (mantissa << exponent) as i64, 1);
+        }
+        let shift = -exponent as u32;
+        let (mut numer, mut denom) = if shift > 127 {
+            (
+                mantissa.checked_shr(shift - 127).unwrap_or(0) as u128,
+                1u128 << 127,
+            )
+        } else {
+            (mantissa as u128, 1u128 << shift)
+        };
+
+        let max_numer = i64::MAX as u128;
+        let max_denom = max_denom as u128;
+        let error = |p: u128, q: u128| (x - Self::from(p).unwrap() / Self::from(q).unwrap()).abs();
+
+        // The last two convergents, starting from 0/1 and 1/0.
+        let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+        loop {
+            let a = numer / denom;
+            let next = a
+                .checked_mul(p1)
+                .and_then(|p| p.checked_add(p0))
+                .zip(a.checked_mul(q1).and_then(|q| q.checked_add(q0)))
+                .filter(|&(p, q)| p <= max_numer && q <= max_denom);
+            match next {
+                Some((p, q)) => {
+                    p0 = p1;
+                    q0 = q1;
+                    p1 = p;
+                    q1 = q;
+                }
+                None => {
+                    // Take the largest semiconvergent that is within the
+                    // bounds, if it is closer than the last convergent. This
+                    // is never reached before the first convergent, since
+                    // `x` fits in an `i64`.
+                    let mut k = (max_denom - q0) / q1;
+                    if let Some(limit) = (max_numer - p0).checked_div(p1) {
+                        k = k.min(limit);
+                    }
+                    let (p, q) = (p0 + k * p1, q0 + k * q1);
+                    if 2 * k > a || (2 * k == a && error(p, q) < error(p1, q1)) {
+                        p1 = p;
+                        q1 = q;
+                    }
+                    break;
+                }
+            }
+            let rem = numer % denom;
+            if rem == 0 {
+                break;
+            }
+            numer = denom;
+            denom = rem;
+        }
+        (sign * p1 as i64, q1 as u64)
+    }
+
+    /// Converts `self` to an `f32`, reporting an error if the value changes.
+    ///
+    /// NaN and infinities convert to the same kind of value. A finite value
+    /// that becomes infinite gives `Overflow`, and a nonzero value that
+    /// becomes subnormal or zero gives `Underflow`, even if it happens to be
+    /// exact. Otherwise, a value that is rounded gives `PrecisionLoss`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::{FloatCore, NarrowError};
+    ///
+    /// assert_eq!(FloatCore::narrow_to_f32_checked(0.5f64), Ok(0.5f32));
+    /// assert_eq!(FloatCore::narrow_to_f32_checked(1e300f64), Err(NarrowError::Overflow));
+    /// assert_eq!(FloatCore::narrow_to_f32_checked(1e-40f64), Err(NarrowError::Underflow));
+    /// assert_eq!(FloatCore::narrow_to_f32_checked(0.1f64), Err(NarrowError::PrecisionLoss));
+    /// ```
+    fn narrow_to_f32_checked(self) -> Result<f32, NarrowError> {
+        // Every `FloatCore` value should be exact as an `f64`.
+        let x = self.to_f64().unwrap_or(f64::NAN);
+        let y = x as f32;
+        if !x.is_finite() {
+            Ok(y)
+        } else if y.is_infinite() {
+            Err(NarrowError::Overflow)
+        } else if x != 0.0 && !y.is_normal() {
+            Err(NarrowError::Underflow)
+        } else if y as f64 != x {
+            Err(NarrowError::PrecisionLoss)
+        } else {
+            Ok(y)
+        }
+    }
+}
+
+/// The reason a value could not be converted exactly by
+/// [`FloatCore::narrow_to_f32_checked`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NarrowError {
+    /// The value is finite, but too large for the target type.
+    Overflow,
+    /// The value is nonzero, but too small to be a normal number of the
+    /// target type.
+    Underflow,
+    /// The value is in range, but had to be rounded.
+    PrecisionLoss,
+}
+
+impl fmt::Display for NarrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match *self {
+            NarrowError::Overflow => "value is too large for the target type",
+            NarrowError::Underflow => "value is too small for the target type",
+            NarrowError::PrecisionLoss => "value is not exactly representable in the target type",
+        };
+
+        description.fmt(f)
+    }
+}
+
+/// A running mean and variance, updated one value at a time with Welford's
+/// algorithm.
+///
+/// This needs only one pass over the data and stays accurate when the values
+/// are large compared to their spread, unlike the sum of squares.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::float::Variance;
+///
+/// let mut stats = Variance::new();
+/// stats.extend([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.0));
+/// assert_eq!(stats.variance(), Some(4.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Variance<T> {
+    count: u64,
+    mean: T,
+    m2: T,
+}
+
+impl<T: FloatCore> Variance<T> {
+    /// Creates an accumulator with no values.
+    pub fn new() -> Self {
+        Variance {
+            count: 0,
+            mean: T::zero(),
+            m2: T::zero(),
+        }
+    }
+
+    /// Adds `x` to the values seen so far.
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean = self.mean + delta / <T as NumCast>::from(self.count).unwrap();
+        self.m2 = self.m2 + delta * (x - self.mean);
+    }
+
+    /// Returns the number of values seen so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values, or `None` if there are none.
+    pub fn mean(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Returns the population variance of the values, dividing by the
+    /// count, or `None` if there are none.
+    pub fn variance(&self) -> Option<T> {
+        self.m2_over(self.count)
+    }
+
+    /// Returns the sample variance of the values, dividing by one less than
+    /// the count, or `None` if there are fewer than two.
+    pub fn sample_variance(&self) -> Option<T> {
+        self.m2_over(self.count.checked_sub(1)?)
+    }
+
+    fn m2_over(&self, n: u64) -> Option<T> {
+        if n == 0 {
+            None
+        } else {
+            Some(self.m2 / <T as NumCast>::from(n).unwrap())
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: FloatCore + Float> Variance<T> {
+    /// Returns the population standard deviation of the values, or `None` if
+    /// there are none.
+    ///
+    /// This is only available with the `std` feature, or with the `libm`
+    /// feature otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::float::Variance;
+    ///
+    /// let stats: Variance<f64> = [1.0, 3.0].into_iter().collect();
+    /// assert_eq!(stats.std_dev(), Some(1.0));
+    /// ```
+    pub fn std_dev(&self) -> Option<T> {
+        self.variance().map(Float::sqrt)
+    }
+}
+
+impl<T: FloatCore> Default for Variance<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatCore> Extend<T> for Variance<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T: FloatCore> FromIterator<T> for Variance<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Returns the smallest positive subnormal value of `T`.
+fn min_subnormal<T: FloatCore>() -> T {
+    T::min_positive_value() * T::epsilon()
+}
+
+/// Returns the position of `|x|` among the non-negative values of `T`, in
+/// order from zero, which must not be NaN.
+fn ulp_ordinal<T: FloatCore>(x: T) -> u64 {
+    let (mantissa, exponent, _) = x.integer_decode();
+    let (normal_mantissa, normal_exponent, _) = T::min_positive_value().integer_decode();
+    if exponent < normal_exponent {
+        // Subnormals and zero are decoded with the mantissa shifted up by one,
+        // into the exponent of the smallest normal.
+        mantissa >> 1
+    } else {
+        (exponent - normal_exponent) as u64 * normal_mantissa + mantissa
+    }
+}
+
+/// Returns the exponents of the smallest and largest normal powers of two.
+fn exponent_range<T: FloatCore>() -> (i32, i32) {
+    fn exponent<T: FloatCore>(x: T) -> i32 {
+        let (mantissa, exponent, _) = x.integer_decode();
+        exponent as i32 + 63 - mantissa.leading_zeros() as i32
+    }
+    (exponent(T::min_positive_value()), exponent(T::max_value()))
+}
+
+/// Returns `2^exp`, which must be a finite normal number.
+fn pow2<T: FloatCore>(exp: i32) -> T {
+    (T::one() + T::one()).powi(exp)
 }
 
 impl FloatCore for f32 {
@@ -2325,6 +2819,121 @@ macro_rules! totalorder_impl {
 totalorder_impl!(f64, i64, u64, 64);
 totalorder_impl!(f32, i32, u32, 32);
 
+/// Compares two floats, ordering NaN relative to every other value as given by
+/// `nan_ordering`.
+///
+/// If only `a` is NaN, `nan_ordering` is returned, and if only `b` is NaN its
+/// reverse is returned, so `Ordering::Greater` sorts NaNs last and
+/// `Ordering::Less` sorts them first. Two NaNs compare equal. Otherwise, this
+/// is the usual partial comparison, so `-0.0` and `0.0` are equal.
+///
+/// Unlike [`TotalOrder::total_cmp`], the placement of NaN does not depend on
+/// its sign bit, and can be chosen by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::float::cmp_or;
+/// use std::cmp::Ordering;
+///
+/// let mut v = [2.0, f64::NAN, -1.0, 0.5];
+/// v.sort_by(|&a, &b| cmp_or(a, b, Ordering::Less));
+/// assert!(v[0].is_nan());
+/// assert_eq!(v[1..], [-1.0, 0.5, 2.0]);
+///
+/// assert_eq!(cmp_or(1.0, f32::NAN, Ordering::Greater), Ordering::Less);
+/// ```
+#[inline]
+pub fn cmp_or<T: FloatCore>(a: T, b: T, nan_ordering: Ordering) -> Ordering {
+    match a.partial_cmp(&b) {
+        Some(ord) => ord,
+        None => match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => nan_ordering,
+            _ => nan_ordering.reverse(),
+        },
+    }
+}
+
+/// Uniform checks for special floating point values, implemented for both
+/// integers and floats.
+///
+/// Integers are always finite and never NaN, so generic code can use these
+/// checks without distinguishing between integer and float types.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::float::FiniteCheck;
+///
+/// fn all_finite<T: FiniteCheck>(values: &[T]) -> bool {
+///     values.iter().all(FiniteCheck::is_finite)
+/// }
+///
+/// assert!(all_finite(&[1u8, 2, 3]));
+/// assert!(all_finite(&[1.0, 2.0, 3.0]));
+/// assert!(!all_finite(&[1.0, f64::INFINITY]));
+/// ```
+pub trait FiniteCheck {
+    /// Returns `true` if this value is neither infinite nor NaN.
+    ///
+    /// This is always `true` for integers.
+    fn is_finite(&self) -> bool;
+
+    /// Returns `true` if this value is NaN.
+    ///
+    /// This is always `false` for integers.
+    fn is_nan(&self) -> bool;
+}
+
+macro_rules! finite_check_int_impl {
+    ($($t:ty)*) => {$(
+        impl FiniteCheck for $t {
+            #[inline]
+            fn is_finite(&self) -> bool {
+                true
+            }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                false
+            }
+        }
+    )*};
+}
+
+finite_check_int_impl!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! finite_check_float_impl {
+    ($($t:ty)*) => {$(
+        impl FiniteCheck for $t {
+            #[inline]
+            fn is_finite(&self) -> bool {
+                <$t>::is_finite(*self)
+            }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                <$t>::is_nan(*self)
+            }
+        }
+    )*};
+}
+
+finite_check_float_impl!(f32 f64);
+
+impl<T: FiniteCheck> FiniteCheck for core::num::Wrapping<T> {
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::f64::consts;
@@ -2457,6 +3066,349 @@ mod tests {
         test_subnormal::<f32>();
     }
 
+    #[test]
+    fn variance() {
+        use crate::float::Variance;
+
+        let empty = Variance::<f64>::new();
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.variance(), None);
+        assert_eq!(empty.sample_variance(), None);
+
+        let one: Variance<f32> = core::iter::once(3.0).collect();
+        assert_eq!(one.mean(), Some(3.0));
+        assert_eq!(one.variance(), Some(0.0));
+        assert_eq!(one.sample_variance(), None);
+
+        // Compare against the two-pass computation.
+        let data = [0.5, 2.25, -1.0, 8.0, 3.5, 3.5, -4.75, 10.0, 0.125];
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let m2 = data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+        let stats: Variance<f64> = data.iter().copied().collect();
+        assert_eq!(stats.count(), data.len() as u64);
+        assert!((stats.mean().unwrap() - mean).abs() < 1e-15);
+        assert!((stats.variance().unwrap() - m2 / n).abs() < 1e-13);
+        assert!((stats.sample_variance().unwrap() - m2 / (n - 1.0)).abs() < 1e-13);
+
+        // A large offset would wipe out the spread in a sum of squares.
+        let mut stats = Variance::new();
+        stats.extend([4.0, 7.0, 13.0, 16.0].iter().map(|x| x + 1e9));
+        assert_eq!(stats.mean(), Some(1e9 + 10.0));
+        assert_eq!(stats.variance(), Some(22.5));
+        assert_eq!(stats.sample_variance(), Some(30.0));
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_eq!(stats.std_dev(), Some(22.5f64.sqrt()));
+    }
+
+    #[test]
+    fn ulps_between() {
+        use crate::float::FloatCore;
+
+        macro_rules! check {
+            ($($t:ident)+) => {$(
+                let values = [
+                    0.0,
+                    $t::from_bits(1),
+                    $t::MIN_POSITIVE,
+                    0.1,
+                    1.0,
+                    3.5,
+                    $t::MAX,
+                    $t::INFINITY,
+                ];
+                for &x in &values {
+                    assert_eq!(FloatCore::ulps_between(x, x), Some(0));
+                    assert_eq!(FloatCore::ulps_between(-x, -x), Some(0));
+                    if x != $t::INFINITY {
+                        let up = FloatCore::next_up(x);
+                        assert_eq!(FloatCore::ulps_between(x, up), Some(1));
+                        assert_eq!(FloatCore::ulps_between(up, x), Some(1));
+                        assert_eq!(FloatCore::ulps_between(-x, -up), Some(1));
+                        if up != $t::INFINITY {
+                            assert_eq!(FloatCore::ulps_between(x, FloatCore::next_up(up)), Some(2));
+                        }
+                    }
+                    assert_eq!(FloatCore::ulps_between(x, $t::NAN), None);
+                }
+
+                // Counting across binades and subnormals matches the bit patterns.
+                for &x in &values {
+                    for &y in &values {
+                        let expected = x.to_bits().max(y.to_bits()) - x.to_bits().min(y.to_bits());
+                        assert_eq!(FloatCore::ulps_between(x, y), Some(expected as u64));
+                    }
+                }
+
+                assert_eq!(FloatCore::ulps_between(0.0 as $t, -0.0), Some(0));
+                assert_eq!(FloatCore::ulps_between(-0.0, $t::from_bits(1)), Some(1));
+                assert_eq!(FloatCore::ulps_between(-1.0 as $t, 1.0), None);
+                assert_eq!(FloatCore::ulps_between($t::NAN, $t::NAN), None);
+            )+};
+        }
+
+        check!(f32 f64);
+    }
+
+    #[test]
+    fn next_up_down() {
+        use crate::float::FloatCore;
+
+        macro_rules! check {
+            ($($f:ident)+) => {$({
+                let values = [
+                    0.0, -0.0, 1.0, -1.0, 2.0, -2.0, 0.1, -0.1, 3.75, -1e30,
+                    $f::MAX, $f::MIN, $f::MIN_POSITIVE, -$f::MIN_POSITIVE,
+                    $f::MIN_POSITIVE * 2.0, $f::MIN_POSITIVE / 2.0, $f::MIN_POSITIVE / 3.0,
+                    $f::from_bits(1), -$f::from_bits(1), $f::from_bits(2), -$f::from_bits(2),
+                    $f::EPSILON, $f::INFINITY, $f::NEG_INFINITY,
+                ];
+                for &x in &values {
+                    let bits = x.to_bits();
+                    let sign = bits >> ($f::to_bits(0.0).count_zeros() - 1);
+                    // Reference implementation stepping the bit pattern.
+                    let up = if x == $f::INFINITY {
+                        x
+                    } else if x == 0.0 {
+                        $f::from_bits(1)
+                    } else if sign == 0 {
+                        $f::from_bits(bits + 1)
+                    } else {
+                        $f::from_bits(bits - 1)
+                    };
+                    let down = if x == $f::NEG_INFINITY {
+                        x
+                    } else if x == 0.0 {
+                        -$f::from_bits(1)
+                    } else if sign == 0 {
+                        $f::from_bits(bits - 1)
+                    } else {
+                        $f::from_bits(bits + 1)
+                    };
+                    assert_eq!(FloatCore::next_up(x).to_bits(), up.to_bits(), "next_up({:e})", x);
+                    assert_eq!(FloatCore::next_down(x).to_bits(), down.to_bits(), "next_down({:e})", x);
+                }
+
+                // Stepping toward zero from the smallest subnormals keeps the sign.
+                assert_eq!(FloatCore::next_up(-$f::from_bits(1)).to_bits(), (-0.0 as $f).to_bits());
+                assert_eq!(FloatCore::next_down($f::from_bits(1)).to_bits(), (0.0 as $f).to_bits());
+                assert!(FloatCore::next_up($f::NAN).is_nan());
+                assert!(FloatCore::next_down($f::NAN).is_nan());
+            })+};
+        }
+
+        check!(f32 f64);
+    }
+
+    #[test]
+    fn finite_check() {
+        use super::FiniteCheck;
+        use core::num::Wrapping;
+
+        macro_rules! check_int {
+            ($($t:ident)*) => {$(
+                assert!(FiniteCheck::is_finite(&$t::MIN));
+                assert!(FiniteCheck::is_finite(&$t::MAX));
+                assert!(!FiniteCheck::is_nan(&$t::MIN));
+                assert!(!FiniteCheck::is_nan(&$t::MAX));
+            )*};
+        }
+        check_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+        macro_rules! check_float {
+            ($($t:ident)*) => {$(
+                assert!(FiniteCheck::is_finite(&$t::MAX));
+                assert!(FiniteCheck::is_finite(&(-0.0 as $t)));
+                assert!(!FiniteCheck::is_finite(&$t::INFINITY));
+                assert!(!FiniteCheck::is_finite(&$t::NEG_INFINITY));
+                assert!(!FiniteCheck::is_finite(&$t::NAN));
+                assert!(FiniteCheck::is_nan(&$t::NAN));
+                assert!(!FiniteCheck::is_nan(&$t::INFINITY));
+                assert!(!FiniteCheck::is_nan(&(1.0 as $t)));
+            )*};
+        }
+        check_float!(f32 f64);
+
+        assert!(FiniteCheck::is_finite(&Wrapping(5u8)));
+        assert!(!FiniteCheck::is_nan(&Wrapping(-5i64)));
+    }
+
+    #[test]
+    fn to_rational_approx() {
+        use crate::float::FloatCore;
+        use core::f64::consts::{E, PI};
+
+        assert_eq!(FloatCore::to_rational_approx(0.5f64, 1), (0, 1));
+        assert_eq!(FloatCore::to_rational_approx(0.5f64, 2), (1, 2));
+        assert_eq!(FloatCore::to_rational_approx(0.0f64, 10), (0, 1));
+        assert_eq!(FloatCore::to_rational_approx(-0.0f64, 10), (0, 1));
+        assert_eq!(FloatCore::to_rational_approx(3.0f32, 10), (3, 1));
+        assert_eq!(FloatCore::to_rational_approx(-0.75f32, 100), (-3, 4));
+        assert_eq!(FloatCore::to_rational_approx(PI, 1), (3, 1));
+        assert_eq!(FloatCore::to_rational_approx(PI, 7), (22, 7));
+        assert_eq!(FloatCore::to_rational_approx(PI, 10), (22, 7));
+        assert_eq!(FloatCore::to_rational_approx(PI, 100), (311, 99));
+        assert_eq!(FloatCore::to_rational_approx(PI, 113), (355, 113));
+        assert_eq!(FloatCore::to_rational_approx(-PI, 7), (-22, 7));
+        assert_eq!(FloatCore::to_rational_approx(E, 1000), (1457, 536));
+        assert_eq!(
+            FloatCore::to_rational_approx(0.1f64, u64::MAX),
+            (3602879701896397, 36028797018963968)
+        );
+        assert_eq!(FloatCore::to_rational_approx(1e-30f64, 1000), (0, 1));
+        assert_eq!(FloatCore::to_rational_approx(0.999f64, 10), (1, 1));
+        assert_eq!(FloatCore::to_rational_approx(0.3f64, 1_000_000), (3, 10));
+        assert_eq!(
+            FloatCore::to_rational_approx(123456.789f64, 1000),
+            (123456789, 1000)
+        );
+        assert_eq!(
+            FloatCore::to_rational_approx(1.0f64 / 3.0, u64::MAX),
+            (6004799503160661, 18014398509481984)
+        );
+        assert_eq!(
+            FloatCore::to_rational_approx(1e-19f64, u64::MAX),
+            (1, 10000000000000000248)
+        );
+        assert_eq!(
+            FloatCore::to_rational_approx(2.0f64.powi(-70), u64::MAX),
+            (0, 1)
+        );
+        assert_eq!(FloatCore::to_rational_approx(5e-324f64, u64::MAX), (0, 1));
+
+        assert_eq!(FloatCore::to_rational_approx(1e30f64, 10), (i64::MAX, 1));
+        assert_eq!(FloatCore::to_rational_approx(-1e30f32, 10), (-i64::MAX, 1));
+        assert_eq!(FloatCore::to_rational_approx(f64::INFINITY, 10), (1, 0));
+        assert_eq!(
+            FloatCore::to_rational_approx(f32::NEG_INFINITY, 10),
+            (-1, 0)
+        );
+        assert_eq!(FloatCore::to_rational_approx(f64::NAN, 10), (0, 0));
+    }
+
+    #[test]
+    fn narrow_to_f32_checked() {
+        use crate::float::{FloatCore, NarrowError::*};
+
+        assert_eq!(FloatCore::narrow_to_f32_checked(1.5f64), Ok(1.5f32));
+        assert_eq!(FloatCore::narrow_to_f32_checked(0.0f64), Ok(0.0f32));
+        assert!(FloatCore::narrow_to_f32_checked(-0.0f64)
+            .unwrap()
+            .is_sign_negative());
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(f32::MAX as f64),
+            Ok(f32::MAX)
+        );
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(f32::MIN_POSITIVE as f64),
+            Ok(f32::MIN_POSITIVE)
+        );
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(16777216.0f64),
+            Ok(16777216.0)
+        );
+        assert_eq!(FloatCore::narrow_to_f32_checked(-2.5f32), Ok(-2.5));
+
+        assert_eq!(FloatCore::narrow_to_f32_checked(1e39f64), Err(Overflow));
+        assert_eq!(FloatCore::narrow_to_f32_checked(-f64::MAX), Err(Overflow));
+        assert_eq!(FloatCore::narrow_to_f32_checked(1e-40f64), Err(Underflow));
+        assert_eq!(FloatCore::narrow_to_f32_checked(-1e-50f64), Err(Underflow));
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(f32::MIN_POSITIVE as f64 / 2.0),
+            Err(Underflow)
+        );
+        assert_eq!(FloatCore::narrow_to_f32_checked(0.1f64), Err(PrecisionLoss));
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(16777217.0f64),
+            Err(PrecisionLoss)
+        );
+
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(f64::INFINITY),
+            Ok(f32::INFINITY)
+        );
+        assert_eq!(
+            FloatCore::narrow_to_f32_checked(f64::NEG_INFINITY),
+            Ok(f32::NEG_INFINITY)
+        );
+        assert!(FloatCore::narrow_to_f32_checked(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn frexp_ldexp() {
+        use crate::float::FloatCore;
+
+        macro_rules! check {
+            ($($f:ident)+) => {$({
+                let values = [
+                    1.0, -1.0, 0.1, -3.75, 1e10, $f::MAX, $f::MIN, $f::MIN_POSITIVE,
+                    $f::MIN_POSITIVE * 0.75, -$f::MIN_POSITIVE / 3.0,
+                    $f::from_bits(1), -$f::from_bits(2), $f::from_bits(5), $f::EPSILON,
+                ];
+                for &x in &values {
+                    let (fraction, exp) = FloatCore::frexp(x);
+                    assert!(fraction.abs() >= 0.5 && fraction.abs() < 1.0, "frexp({:e})", x);
+                    assert_eq!(fraction.is_sign_negative(), x.is_sign_negative());
+                    assert_eq!(FloatCore::ldexp(fraction, exp), x, "frexp({:e})", x);
+                    assert_eq!(FloatCore::ldexp(x, 0), x);
+                    if x.abs() >= $f::MIN_POSITIVE * 32.0 {
+                        assert_eq!(FloatCore::ldexp(FloatCore::ldexp(x, -5), 5), x);
+                    }
+                }
+
+                for &x in &[0.0, -0.0, $f::INFINITY, $f::NEG_INFINITY] {
+                    assert_eq!(FloatCore::frexp(x), (x, 0));
+                    assert_eq!(FloatCore::ldexp(x, 7), x);
+                }
+                assert!(FloatCore::frexp($f::NAN).0.is_nan());
+                assert!(FloatCore::ldexp($f::NAN, 1).is_nan());
+
+                // Overflow and underflow keep the sign.
+                assert_eq!(FloatCore::ldexp(1.0 as $f, i32::MAX), $f::INFINITY);
+                assert_eq!(FloatCore::ldexp(-$f::from_bits(1), i32::MAX), $f::NEG_INFINITY);
+                assert_eq!(FloatCore::ldexp($f::MAX, i32::MIN).to_bits(), 0);
+                assert_eq!(FloatCore::ldexp(-1.0 as $f, i32::MIN).to_bits(), (-0.0 as $f).to_bits());
+                assert_eq!(FloatCore::ldexp($f::from_bits(1), 1), $f::from_bits(2));
+                assert_eq!(FloatCore::ldexp($f::MAX * 0.5, 1), $f::MAX);
+
+                // Rounding to subnormals ties to even, rounding only once.
+                let min_exp = $f::MIN_EXP - $f::MANTISSA_DIGITS as i32;
+                assert_eq!(FloatCore::ldexp(1.0 as $f, min_exp), $f::from_bits(1));
+                assert_eq!(FloatCore::ldexp(1.0 as $f, min_exp - 1), 0.0);
+                assert_eq!(FloatCore::ldexp(1.5 as $f, min_exp - 1), $f::from_bits(1));
+                assert_eq!(FloatCore::ldexp(1.5 as $f, min_exp), $f::from_bits(2));
+                assert_eq!(FloatCore::ldexp(2.5 as $f, min_exp), $f::from_bits(2));
+                assert_eq!(FloatCore::ldexp(1.75 as $f, min_exp - 1), $f::from_bits(1));
+                assert_eq!(FloatCore::ldexp(0.75 as $f, min_exp), $f::from_bits(1));
+                assert_eq!(FloatCore::ldexp(5.0 as $f, min_exp - 2), $f::from_bits(1));
+                assert_eq!(FloatCore::ldexp(-3.0 as $f, min_exp - 1), -$f::from_bits(2));
+            })+};
+        }
+
+        check!(f32 f64);
+    }
+
+    #[test]
+    fn cmp_or() {
+        use super::cmp_or;
+        use core::cmp::Ordering::*;
+
+        for &nan_ord in &[Less, Greater] {
+            assert_eq!(cmp_or(f64::NAN, 1.0, nan_ord), nan_ord);
+            assert_eq!(cmp_or(1.0, f64::NAN, nan_ord), nan_ord.reverse());
+            assert_eq!(cmp_or(-f32::NAN, f32::INFINITY, nan_ord), nan_ord);
+            assert_eq!(
+                cmp_or(f32::NEG_INFINITY, f32::NAN, nan_ord),
+                nan_ord.reverse()
+            );
+            assert_eq!(cmp_or(f64::NAN, -f64::NAN, nan_ord), Equal);
+
+            assert_eq!(cmp_or(1.0, 2.0, nan_ord), Less);
+            assert_eq!(cmp_or(2.0f32, 1.0, nan_ord), Greater);
+            assert_eq!(cmp_or(-0.0, 0.0, nan_ord), Equal);
+        }
+    }
+
     #[test]
     fn total_cmp() {
         use crate::float::TotalOrder;
@@ -1,6 +1,10 @@
+#[cfg(has_num_saturating)]
+use core::num::Saturating;
 use core::num::Wrapping;
 use core::ops::{Add, Mul};
 
+use crate::{FromPrimitive, Num};
+
 /// Defines an additive identity element for `Self`.
 ///
 /// # Laws
@@ -95,6 +99,34 @@ where
     const ZERO: Self = Wrapping(T::ZERO);
 }
 
+#[cfg(has_num_saturating)]
+#[allow(clippy::incompatible_msrv)]
+impl<T: Zero> Zero for Saturating<T>
+where
+    Saturating<T>: Add<Output = Saturating<T>>,
+{
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn set_zero(&mut self) {
+        self.0.set_zero();
+    }
+
+    fn zero() -> Self {
+        Saturating(T::zero())
+    }
+}
+
+#[cfg(has_num_saturating)]
+#[allow(clippy::incompatible_msrv)]
+impl<T: ConstZero> ConstZero for Saturating<T>
+where
+    Saturating<T>: Add<Output = Saturating<T>>,
+{
+    const ZERO: Self = Saturating(T::ZERO);
+}
+
 /// Defines a multiplicative identity element for `Self`.
 ///
 /// # Laws
@@ -196,6 +228,67 @@ where
     const ONE: Self = Wrapping(T::ONE);
 }
 
+#[cfg(has_num_saturating)]
+#[allow(clippy::incompatible_msrv)]
+impl<T: One> One for Saturating<T>
+where
+    Saturating<T>: Mul<Output = Saturating<T>>,
+{
+    fn set_one(&mut self) {
+        self.0.set_one();
+    }
+
+    fn one() -> Self {
+        Saturating(T::one())
+    }
+}
+
+#[cfg(has_num_saturating)]
+#[allow(clippy::incompatible_msrv)]
+impl<T: ConstOne> ConstOne for Saturating<T>
+where
+    Saturating<T>: Mul<Output = Saturating<T>>,
+{
+    const ONE: Self = Saturating(T::ONE);
+}
+
+/// Small integer constants, from `0` to `255`, for any numeric type.
+///
+/// This is implemented for all types that implement `Num` and
+/// `FromPrimitive`.
+pub trait SmallConst: Sized {
+    /// Returns `n` as a value of this type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` cannot be represented by this type, which never happens
+    /// for the primitive numeric types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::SmallConst;
+    ///
+    /// fn half_of_ten<T: SmallConst + std::ops::Div<Output = T>>() -> T {
+    ///     T::from_u8_const(10) / T::from_u8_const(2)
+    /// }
+    ///
+    /// assert_eq!(half_of_ten::<i8>(), 5);
+    /// assert_eq!(half_of_ten::<f32>(), 5.0);
+    /// ```
+    fn from_u8_const(n: u8) -> Self;
+}
+
+impl<T: Num + FromPrimitive> SmallConst for T {
+    #[inline]
+    fn from_u8_const(n: u8) -> Self {
+        match T::from_u8(n) {
+            Some(value) => value,
+            None => panic!("{} cannot be represented by this type", n),
+        }
+    }
+}
+
 // Some helper functions provided for backwards compatibility.
 
 /// Returns the additive identity, `0`.
@@ -210,6 +303,16 @@ pub fn one<T: One>() -> T {
     One::one()
 }
 
+/// Returns the small constant `n`, from `0` to `255`.
+///
+/// # Panics
+///
+/// Panics if `n` cannot be represented by `T`. See [`SmallConst`].
+#[inline(always)]
+pub fn from_u8_const<T: SmallConst>(n: u8) -> T {
+    SmallConst::from_u8_const(n)
+}
+
 #[test]
 fn wrapping_identities() {
     macro_rules! test_wrapping_identities {
@@ -226,6 +329,55 @@ fn wrapping_identities() {
     test_wrapping_identities!(isize i8 i16 i32 i64 usize u8 u16 u32 u64);
 }
 
+#[test]
+fn wrapping_identities_i128() {
+    use crate::Pow;
+
+    macro_rules! test_wrapping_identities {
+        ($($t:ty)+) => {
+            $(
+                assert_eq!(zero::<$t>(), zero::<Wrapping<$t>>().0);
+                assert_eq!(one::<$t>(), one::<Wrapping<$t>>().0);
+                assert_eq!((0 as $t).is_zero(), Wrapping(0 as $t).is_zero());
+                assert_eq!((1 as $t).is_zero(), Wrapping(1 as $t).is_zero());
+                assert_eq!((0 as $t).is_one(), Wrapping(0 as $t).is_one());
+                assert_eq!((1 as $t).is_one(), Wrapping(1 as $t).is_one());
+                assert_eq!(Pow::pow(Wrapping(3 as $t), 5usize).0, 243);
+                assert_eq!(Pow::pow(Wrapping(2 as $t), 128usize).0, 0);
+            )+
+        };
+    }
+
+    test_wrapping_identities!(i128 u128);
+}
+
+#[test]
+#[cfg(has_num_saturating)]
+fn saturating_identities() {
+    macro_rules! test_saturating_identities {
+        ($($t:ty)+) => {
+            $(
+                assert_eq!(zero::<$t>(), zero::<Saturating<$t>>().0);
+                assert_eq!(one::<$t>(), one::<Saturating<$t>>().0);
+                assert_eq!(<$t>::ZERO, <Saturating<$t>>::ZERO.0);
+                assert_eq!(<$t>::ONE, <Saturating<$t>>::ONE.0);
+                assert_eq!((0 as $t).is_zero(), Saturating(0 as $t).is_zero());
+                assert_eq!((1 as $t).is_zero(), Saturating(1 as $t).is_zero());
+                assert_eq!((0 as $t).is_one(), Saturating(0 as $t).is_one());
+                assert_eq!((1 as $t).is_one(), Saturating(1 as $t).is_one());
+            )+
+        };
+    }
+
+    test_saturating_identities!(isize i8 i16 i32 i64 i128 usize u8 u16 u32 u64 u128);
+
+    // Accumulating into a saturating wrapper clamps instead of overflowing.
+    let total = [200u8, 100, 50]
+        .iter()
+        .fold(Saturating::<u8>::zero(), |acc, &x| acc + Saturating(x));
+    assert_eq!(total, Saturating(u8::MAX));
+}
+
 #[test]
 fn wrapping_is_zero() {
     fn require_zero<T: Zero>(_: &T) {}
@@ -236,3 +388,19 @@ fn wrapping_is_one() {
     fn require_one<T: One>(_: &T) {}
     require_one(&Wrapping(42));
 }
+
+#[test]
+fn small_const() {
+    assert_eq!(from_u8_const::<f64>(42), 42.0);
+    assert_eq!(from_u8_const::<f32>(255), 255.0);
+    assert_eq!(from_u8_const::<u8>(255), 255);
+    assert_eq!(from_u8_const::<i128>(7), 7);
+    assert_eq!(from_u8_const::<Wrapping<u16>>(3), Wrapping(3));
+    assert_eq!(<i8 as SmallConst>::from_u8_const(127), 127);
+}
+
+#[test]
+#[should_panic]
+fn small_const_out_of_range() {
+    let _ = from_u8_const::<i8>(128);
+}
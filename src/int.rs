@@ -1,9 +1,12 @@
+use core::fmt;
+use core::iter::{FusedIterator, Rev};
 use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
 
 use crate::bounds::Bounded;
 use crate::ops::checked::*;
 use crate::ops::saturating::Saturating;
-use crate::{Num, NumCast};
+use crate::ops::wrapping::WrappingMul;
+use crate::{Num, NumCast, Signed, Unsigned};
 
 /// Generic trait for primitive integers.
 ///
@@ -108,6 +111,30 @@ pub trait PrimInt:
     /// ```
     fn leading_zeros(self) -> u32;
 
+    /// Returns the number of bits after the most significant bit that are
+    /// equal to it, like the `CLS` instruction.
+    ///
+    /// For signed integers this is how far `self` can be shifted left without
+    /// changing its sign. Unsigned integers are treated by their bit pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::leading_sign_bits(-1i8), 7);
+    /// assert_eq!(PrimInt::leading_sign_bits(0i8), 7);
+    /// assert_eq!(PrimInt::leading_sign_bits(1i32), 30);
+    /// assert_eq!(PrimInt::leading_sign_bits(-4i16), 13);
+    /// ```
+    fn leading_sign_bits(self) -> u32 {
+        if self.leading_zeros() == 0 {
+            self.leading_ones() - 1
+        } else {
+            self.leading_zeros() - 1
+        }
+    }
+
     /// Returns the number of trailing ones in the binary representation
     /// of `self`.
     ///
@@ -356,136 +383,1376 @@ pub trait PrimInt:
     /// assert_eq!(2i32.pow(4), 16);
     /// ```
     fn pow(self, exp: u32) -> Self;
-}
 
-fn one_per_byte<P: PrimInt>() -> P {
-    // i8, u8: return 0x01
-    // i16, u16: return 0x0101 = (0x01 << 8) | 0x01
-    // i32, u32: return 0x01010101 = (0x0101 << 16) | 0x0101
-    // ...
-    let mut ret = P::one();
-    let mut shift = 8;
-    let mut b = ret.count_zeros() >> 3;
-    while b != 0 {
-        ret = (ret << shift) | ret;
-        shift <<= 1;
-        b >>= 1;
+    /// Returns the successor of `self`, i.e. `self + 1`, or `None` if `self`
+    /// is the maximum value of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::checked_succ(41u8), Some(42));
+    /// assert_eq!(PrimInt::checked_succ(u8::MAX), None);
+    /// ```
+    #[inline]
+    fn checked_succ(self) -> Option<Self> {
+        self.checked_add(&Self::one())
     }
-    ret
-}
-
-fn reverse_bits_fallback<P: PrimInt>(i: P) -> P {
-    let rep_01: P = one_per_byte();
-    let rep_03 = (rep_01 << 1) | rep_01;
-    let rep_05 = (rep_01 << 2) | rep_01;
-    let rep_0f = (rep_03 << 2) | rep_03;
-    let rep_33 = (rep_03 << 4) | rep_03;
-    let rep_55 = (rep_05 << 4) | rep_05;
 
-    // code above only used to determine rep_0f, rep_33, rep_55;
-    // optimizer should be able to do it in compile time
-    let mut ret = i.swap_bytes();
-    ret = ((ret & rep_0f) << 4) | ((ret >> 4) & rep_0f);
-    ret = ((ret & rep_33) << 2) | ((ret >> 2) & rep_33);
-    ret = ((ret & rep_55) << 1) | ((ret >> 1) & rep_55);
-    ret
-}
+    /// Returns the predecessor of `self`, i.e. `self - 1`, or `None` if `self`
+    /// is the minimum value of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::checked_pred(-41i8), Some(-42));
+    /// assert_eq!(PrimInt::checked_pred(i8::MIN), None);
+    /// ```
+    #[inline]
+    fn checked_pred(self) -> Option<Self> {
+        self.checked_sub(&Self::one())
+    }
 
-macro_rules! prim_int_impl {
-    ($T:ty, $S:ty, $U:ty) => {
-        impl PrimInt for $T {
-            #[inline]
-            fn count_ones(self) -> u32 {
-                <$T>::count_ones(self)
-            }
+    /// Multiplies `self` by `10^n`, returning `None` if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::checked_pow10(-25i32, 2), Some(-2500));
+    /// assert_eq!(PrimInt::checked_pow10(3u8, 2), None);
+    /// assert_eq!(PrimInt::checked_pow10(0u8, 100), Some(0));
+    /// ```
+    fn checked_pow10(self, n: u32) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self);
+        }
+        let ten = <Self as NumCast>::from(10)?;
+        let mut acc = self;
+        for _ in 0..n {
+            acc = acc.checked_mul(&ten)?;
+        }
+        Some(acc)
+    }
 
-            #[inline]
-            fn count_zeros(self) -> u32 {
-                <$T>::count_zeros(self)
-            }
+    /// Keeps only the low `n` bits of `self`, setting all higher bits to zero.
+    ///
+    /// If `n` is greater than or equal to the number of bits in `Self`, the
+    /// value is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::truncate_to_bits(0b1011_0110u8, 4), 0b0110);
+    /// assert_eq!(PrimInt::truncate_to_bits(-1i16, 12), 0x0fff);
+    /// assert_eq!(PrimInt::truncate_to_bits(-1i16, 0), 0);
+    /// ```
+    fn truncate_to_bits(self, n: u32) -> Self {
+        let bits = Self::zero().count_zeros();
+        if n >= bits {
+            self
+        } else if n == 0 {
+            Self::zero()
+        } else {
+            self & (!Self::zero()).unsigned_shr(bits - n)
+        }
+    }
 
-            #[inline]
-            fn leading_ones(self) -> u32 {
-                <$T>::leading_ones(self)
+    /// Treats the low `n` bits of `self` as a two's complement value, copying
+    /// bit `n - 1` into all higher bits.
+    ///
+    /// If `n` is greater than or equal to the number of bits in `Self`, the
+    /// value is returned unchanged, and if `n` is zero the result is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::sign_extend_from_bits(0b1000u8, 4), 0b1111_1000);
+    /// assert_eq!(PrimInt::sign_extend_from_bits(0b0111u8, 4), 0b0111);
+    /// assert_eq!(PrimInt::sign_extend_from_bits(0xffi32, 8), -1);
+    /// ```
+    fn sign_extend_from_bits(self, n: u32) -> Self {
+        let bits = Self::zero().count_zeros();
+        if n >= bits {
+            self
+        } else if n == 0 {
+            Self::zero()
+        } else {
+            let low = self.truncate_to_bits(n);
+            if low.unsigned_shr(n - 1).is_one() {
+                low | (!Self::zero()).unsigned_shl(n)
+            } else {
+                low
             }
+        }
+    }
 
-            #[inline]
-            fn leading_zeros(self) -> u32 {
-                <$T>::leading_zeros(self)
-            }
+    /// Returns an iterator over the digits of the absolute value of `self` in
+    /// the given `radix`, from least to most significant.
+    ///
+    /// Zero has a single digit, `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert!(PrimInt::digits(123u32, 10).eq([3, 2, 1]));
+    /// assert!(PrimInt::digits(-0x1fi8, 16).eq([15, 1]));
+    /// assert!(PrimInt::digits(0u64, 2).eq([0]));
+    /// ```
+    fn digits(self, radix: u32) -> Digits<Self> {
+        Digits::new(self, radix)
+    }
 
-            #[inline]
-            fn trailing_ones(self) -> u32 {
-                <$T>::trailing_ones(self)
-            }
+    /// Returns an iterator over the digits of the absolute value of `self` in
+    /// the given `radix`, from most to least significant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert!(PrimInt::digits_be(123u32, 10).eq([1, 2, 3]));
+    /// ```
+    fn digits_be(self, radix: u32) -> Rev<Digits<Self>> {
+        self.digits(radix).rev()
+    }
 
-            #[inline]
-            fn trailing_zeros(self) -> u32 {
-                <$T>::trailing_zeros(self)
-            }
+    /// Returns the sum of the digits of the absolute value of `self` in the
+    /// given `radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::digit_sum(1234u32, 10), 10);
+    /// assert_eq!(PrimInt::digit_sum(-0xffi16, 16), 30);
+    /// ```
+    fn digit_sum(self, radix: u32) -> u32 {
+        self.digits(radix).map(|digit| digit as u32).sum()
+    }
 
-            #[inline]
-            fn rotate_left(self, n: u32) -> Self {
-                <$T>::rotate_left(self, n)
-            }
+    /// Computes a Luhn-style checksum of the digits of the absolute value of
+    /// `self` in the given `radix`, reduced modulo `modulus`.
+    ///
+    /// Starting from the least significant digit, every second digit is
+    /// doubled, and a doubled value of `radix` or more is replaced by the sum
+    /// of its two digits. The result is the sum of all digits modulo
+    /// `modulus`, so a number with a valid Luhn check digit has
+    /// `checksum_mod(10, 10) == 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36, or if `modulus` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::checksum_mod(79927398713u64, 10, 10), 0);
+    /// assert_eq!(PrimInt::checksum_mod(79927398710u64, 10, 10), 7);
+    /// ```
+    fn checksum_mod(self, radix: u32, modulus: u32) -> u32 {
+        let sum: u32 = self
+            .digits(radix)
+            .enumerate()
+            .map(|(i, digit)| {
+                let digit = digit as u32;
+                if i % 2 == 0 {
+                    digit
+                } else if digit * 2 >= radix {
+                    digit * 2 - (radix - 1)
+                } else {
+                    digit * 2
+                }
+            })
+            .sum();
+        sum % modulus
+    }
 
-            #[inline]
-            fn rotate_right(self, n: u32) -> Self {
-                <$T>::rotate_right(self, n)
-            }
+    /// Writes the decimal representation of `self` to the start of `buf`,
+    /// returning the written part as a string, or `None` if `buf` is too
+    /// small.
+    ///
+    /// Negative values are written with a leading `-`. Nothing is written if
+    /// the buffer is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// let mut buf = [0u8; 4];
+    /// assert_eq!(PrimInt::write_decimal(-123i32, &mut buf), Some("-123"));
+    /// assert_eq!(PrimInt::write_decimal(12345u32, &mut buf), None);
+    /// ```
+    fn write_decimal(self, buf: &mut [u8]) -> Option<&str> {
+        let digits = self.digits(10);
+        let negative = self < Self::zero();
+        let len = digits.len() + negative as usize;
+        let out = buf.get_mut(..len)?;
+        if negative {
+            out[0] = b'-';
+        }
+        for (byte, digit) in out[negative as usize..].iter_mut().zip(digits.rev()) {
+            *byte = b'0' + digit;
+        }
+        core::str::from_utf8(out).ok()
+    }
 
-            #[inline]
-            fn signed_shl(self, n: u32) -> Self {
-                ((self as $S) << n) as $T
-            }
+    /// Returns `self` modulo `m`, always in the range `[0, m)`.
+    ///
+    /// Unlike `%`, the result is never negative, even if `self` is, which
+    /// makes it suitable for mapping values to buckets. For positive `m`
+    /// this is the same as `rem_euclid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::positive_mod(-1i32, 7), 6);
+    /// assert_eq!(PrimInt::positive_mod(15u8, 4), 3);
+    /// assert_eq!(PrimInt::positive_mod(i64::MIN, 10), 2);
+    /// ```
+    fn positive_mod(self, m: Self) -> Self {
+        assert!(m > Self::zero(), "modulus must be positive");
+        let r = self % m;
+        if r < Self::zero() {
+            r + m
+        } else {
+            r
+        }
+    }
 
-            #[inline]
-            fn signed_shr(self, n: u32) -> Self {
-                ((self as $S) >> n) as $T
-            }
+    /// Returns `self` modulo `m` as a `u32`, always in the range `[0, m)`.
+    ///
+    /// This is [`positive_mod`](PrimInt::positive_mod) with a `u32` modulus,
+    /// which is usually the size of a table of buckets. Values that already
+    /// fit in a `u32` are reduced with a single `u32` remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::PrimInt;
+    ///
+    /// assert_eq!(PrimInt::positive_mod_u32(-1i64, 7), 6);
+    /// assert_eq!(PrimInt::positive_mod_u32(u128::MAX, 1000), 455);
+    /// ```
+    fn positive_mod_u32(self, m: u32) -> u32 {
+        assert!(m > 0, "modulus must be positive");
+        if let Some(x) = self.to_u32() {
+            x % m
+        } else if let Some(x) = self.to_i128() {
+            x.rem_euclid(m as i128) as u32
+        } else {
+            // only values above `i128::MAX` are left
+            (self.to_u128().unwrap() % m as u128) as u32
+        }
+    }
+}
 
-            #[inline]
-            fn unsigned_shl(self, n: u32) -> Self {
-                ((self as $U) << n) as $T
-            }
+/// An iterator over the digits of an integer.
+///
+/// This `struct` is created by the [`digits`] method on [`PrimInt`]. See its
+/// documentation for more.
+///
+/// [`digits`]: PrimInt::digits
+#[derive(Clone, Debug)]
+pub struct Digits<T> {
+    // The remaining digits, kept non-positive for signed types so that the
+    // magnitude of `MIN` is representable.
+    value: T,
+    radix: T,
+    // The place value of the most significant remaining digit, with the same
+    // sign as `value`.
+    high: T,
+    len: usize,
+}
 
-            #[inline]
-            fn unsigned_shr(self, n: u32) -> Self {
-                ((self as $U) >> n) as $T
-            }
+impl<T: PrimInt> Digits<T> {
+    fn new(value: T, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, got {}",
+            radix
+        );
+        let radix = T::from(radix).unwrap();
+        let signed = T::min_value() < T::zero();
+        let (value, mut high) = if signed {
+            let value = if value > T::zero() {
+                T::zero() - value
+            } else {
+                value
+            };
+            (value, T::zero() - T::one())
+        } else {
+            (value, T::one())
+        };
+        // Compare against `value / radix` rather than `high * radix` to avoid
+        // overflow, including `MIN / -1`.
+        let rest = value / radix;
+        let mut len = 1;
+        while (signed && rest <= high) || (!signed && rest >= high) {
+            high = high * radix;
+            len += 1;
+        }
+        Digits {
+            value,
+            radix,
+            high,
+            len,
+        }
+    }
+}
 
-            #[inline]
-            fn swap_bytes(self) -> Self {
-                <$T>::swap_bytes(self)
-            }
+impl<T: PrimInt> Iterator for Digits<T> {
+    type Item = u8;
 
-            #[inline]
-            fn reverse_bits(self) -> Self {
-                <$T>::reverse_bits(self)
-            }
+    fn next(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let digit = self.value % self.radix;
+        let digit = if digit < T::zero() {
+            T::zero() - digit
+        } else {
+            digit
+        };
+        self.value = self.value / self.radix;
+        self.high = self.high / self.radix;
+        self.len -= 1;
+        digit.to_u8()
+    }
 
-            #[inline]
-            fn from_be(x: Self) -> Self {
-                <$T>::from_be(x)
-            }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
 
-            #[inline]
-            fn from_le(x: Self) -> Self {
-                <$T>::from_le(x)
-            }
+impl<T: PrimInt> DoubleEndedIterator for Digits<T> {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let digit = self.value / self.high;
+        self.value = self.value % self.high;
+        self.high = self.high / self.radix;
+        self.len -= 1;
+        digit.to_u8()
+    }
+}
 
-            #[inline]
-            fn to_be(self) -> Self {
-                <$T>::to_be(self)
-            }
+impl<T: PrimInt> ExactSizeIterator for Digits<T> {}
 
-            #[inline]
-            fn to_le(self) -> Self {
-                <$T>::to_le(self)
-            }
+impl<T: PrimInt> FusedIterator for Digits<T> {}
 
-            #[inline]
-            fn pow(self, exp: u32) -> Self {
+/// Reads consecutive bit fields out of an integer.
+///
+/// Fields are consumed either from the most significant end, as they are
+/// usually laid out in protocol headers, or from the least significant end.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::BitReader;
+///
+/// let mut header = BitReader::msb_first(0x4500_0054u32);
+/// assert_eq!(header.read_bits(4), 4); // version
+/// assert_eq!(header.read_bits(4), 5); // header length
+/// assert_eq!(header.read_bits(8), 0); // type of service
+/// assert_eq!(header.read_bits(16), 84); // total length
+/// assert_eq!(header.remaining(), 0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<T> {
+    value: T,
+    remaining: u32,
+    msb_first: bool,
+}
+
+impl<T: PrimInt> BitReader<T> {
+    /// Creates a reader that consumes `value` from its most significant bit.
+    pub fn msb_first(value: T) -> Self {
+        BitReader {
+            value,
+            remaining: T::zero().count_zeros(),
+            msb_first: true,
+        }
+    }
+
+    /// Creates a reader that consumes `value` from its least significant bit.
+    pub fn lsb_first(value: T) -> Self {
+        BitReader {
+            value,
+            remaining: T::zero().count_zeros(),
+            msb_first: false,
+        }
+    }
+
+    /// Returns the number of bits that have not been read yet.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Reads the next `n` bits, returned in the low bits of the result with
+    /// the higher bits set to zero, even for signed types.
+    ///
+    /// Reading zero bits returns zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the number of remaining bits.
+    pub fn read_bits(&mut self, n: u32) -> T {
+        assert!(n <= self.remaining, "not enough bits left to read");
+        let bits = T::zero().count_zeros();
+        if n == 0 {
+            return T::zero();
+        }
+        self.remaining -= n;
+        if n == bits {
+            return core::mem::replace(&mut self.value, T::zero());
+        }
+        if self.msb_first {
+            let field = self.value.unsigned_shr(bits - n);
+            self.value = self.value.unsigned_shl(n);
+            field
+        } else {
+            let field = self.value.truncate_to_bits(n);
+            self.value = self.value.unsigned_shr(n);
+            field
+        }
+    }
+}
+
+/// Returns an iterator over the bit-reversal permutation of `0..2^n_bits`,
+/// as used to reorder the input of a radix-2 FFT.
+///
+/// Each index `i` is mapped to the integer whose low `n_bits` bits are the
+/// low `n_bits` bits of `i` in reverse order. The indices are computed on
+/// the fly, without allocating.
+///
+/// # Panics
+///
+/// Panics if `n_bits` is not less than the number of bits in `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::bit_reversed_indices;
+///
+/// assert!(bit_reversed_indices(2).eq([0, 2, 1, 3]));
+/// assert!(bit_reversed_indices(0).eq([0]));
+/// ```
+pub fn bit_reversed_indices(n_bits: u32) -> BitReversedIndices {
+    assert!(n_bits < usize::BITS, "too many bits for usize indices");
+    BitReversedIndices {
+        next: 0,
+        end: 1 << n_bits,
+        n_bits,
+    }
+}
+
+/// An iterator over the bit-reversal permutation of `0..2^n_bits`.
+///
+/// This `struct` is created by [`bit_reversed_indices`]. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+pub struct BitReversedIndices {
+    next: usize,
+    end: usize,
+    n_bits: u32,
+}
+
+impl BitReversedIndices {
+    fn reverse(&self, i: usize) -> usize {
+        if self.n_bits == 0 {
+            0
+        } else {
+            i.reverse_bits() >> (usize::BITS - self.n_bits)
+        }
+    }
+}
+
+impl Iterator for BitReversedIndices {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.next == self.end {
+            return None;
+        }
+        let i = self.reverse(self.next);
+        self.next += 1;
+        Some(i)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitReversedIndices {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.next == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.reverse(self.end))
+    }
+}
+
+impl ExactSizeIterator for BitReversedIndices {}
+
+impl FusedIterator for BitReversedIndices {}
+
+/// Generic trait relating a primitive integer to its same-width signed and unsigned types.
+///
+/// This lets generic code name the opposite-signedness counterpart of an integer type, e.g.
+/// `<u32 as Signedness>::Signed` is `i32` and `<i64 as Signedness>::Unsigned` is `u64`. The
+/// `cast_signed` and `cast_unsigned` methods reinterpret the bits of a value as the counterpart
+/// type, like the `as` operator.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::Signedness;
+///
+/// fn unsigned_abs<T: Signedness>(x: T::Signed) -> T::Unsigned {
+///     if x < T::Signed::zero() {
+///         (!x).cast_unsigned() + T::Unsigned::one()
+///     } else {
+///         x.cast_unsigned()
+///     }
+/// }
+///
+/// # use num_traits::{One, Zero};
+/// assert_eq!(unsigned_abs::<u8>(-128), 128u8);
+/// assert_eq!(unsigned_abs::<u32>(42), 42u32);
+/// ```
+pub trait Signedness: PrimInt {
+    /// The signed integer type with the same width as `Self`.
+    type Signed: Signedness<Signed = Self::Signed, Unsigned = Self::Unsigned> + Signed;
+
+    /// The unsigned integer type with the same width as `Self`.
+    type Unsigned: Signedness<Signed = Self::Signed, Unsigned = Self::Unsigned> + Unsigned;
+
+    /// Reinterprets the bits of `self` as the signed type of the same width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::Signedness;
+    ///
+    /// assert_eq!(Signedness::cast_signed(255u8), -1i8);
+    /// assert_eq!(Signedness::cast_signed(-1i8), -1i8);
+    /// ```
+    fn cast_signed(self) -> Self::Signed;
+
+    /// Reinterprets the bits of `self` as the unsigned type of the same width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::Signedness;
+    ///
+    /// assert_eq!(Signedness::cast_unsigned(-1i8), 255u8);
+    /// assert_eq!(Signedness::cast_unsigned(255u8), 255u8);
+    /// ```
+    fn cast_unsigned(self) -> Self::Unsigned;
+
+    /// Returns the shortest signed distance from `other` to `self` on a
+    /// circle of `2^BITS` values, as in the serial number arithmetic of
+    /// RFC 1982.
+    ///
+    /// The result is positive if `self` is ahead of `other`, even across the
+    /// point where the values wrap around. When the two are exactly half the
+    /// circle apart the direction is ambiguous, and the result is the
+    /// negative `Self::Signed::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::Signedness;
+    ///
+    /// assert_eq!(Signedness::wrapping_distance(1u8, 255), 2);
+    /// assert_eq!(Signedness::wrapping_distance(255u8, 1), -2);
+    /// assert_eq!(Signedness::wrapping_distance(100u32, 90), 10);
+    /// ```
+    fn wrapping_distance(self, other: Self) -> Self::Signed {
+        wrapping_sub_bits(self.cast_unsigned(), other.cast_unsigned()).cast_signed()
+    }
+
+    /// Returns the absolute difference `|self - other|` as the unsigned type
+    /// of the same width.
+    ///
+    /// For the primitive integers this is the same as `abs_diff`, which always
+    /// fits, but the name leaves room for other implementations to saturate at
+    /// `Self::Unsigned::max_value()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::Signedness;
+    ///
+    /// assert_eq!(Signedness::saturating_abs_diff(&-100i8, &100), 200u8);
+    /// assert_eq!(Signedness::saturating_abs_diff(&3u16, &10), 7u16);
+    /// ```
+    fn saturating_abs_diff(&self, other: &Self) -> Self::Unsigned {
+        let (hi, lo) = if *self >= *other {
+            (*self, *other)
+        } else {
+            (*other, *self)
+        };
+        wrapping_sub_bits(hi.cast_unsigned(), lo.cast_unsigned())
+    }
+
+    /// Converts `self` to the unsigned type `U`, clamping negative values to
+    /// zero and values above `U::max_value()` to that maximum.
+    ///
+    /// This suits a count kept as a sum of signed deltas in a wider type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::Signedness;
+    ///
+    /// assert_eq!((-5i32).saturating_to_unsigned::<u8>(), 0);
+    /// assert_eq!(1000i32.saturating_to_unsigned::<u8>(), 255);
+    /// assert_eq!(1000i32.saturating_to_unsigned::<u16>(), 1000);
+    /// ```
+    fn saturating_to_unsigned<U: Unsigned + Bounded + NumCast>(self) -> U {
+        <U as NumCast>::from(self).unwrap_or_else(|| {
+            if self < Self::zero() {
+                U::min_value()
+            } else {
+                U::max_value()
+            }
+        })
+    }
+}
+
+/// Returns `a - b` modulo `2^BITS`.
+fn wrapping_sub_bits<U: PrimInt>(a: U, b: U) -> U {
+    if a >= b {
+        a - b
+    } else {
+        // `b - a` is nonzero, so this can't overflow.
+        !(b - a) + U::one()
+    }
+}
+
+/// Common bit masks for primitive integers, as associated constants.
+///
+/// For signed types, the masks have the same bit patterns as for the unsigned
+/// type of the same width, so `HIGH_HALF`, `ALL_ONES` and `SIGN_BIT` are
+/// negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::BitMasks;
+///
+/// assert_eq!(u16::LOW_HALF, 0x00ff);
+/// assert_eq!(u16::HIGH_HALF, 0xff00);
+/// assert_eq!(u16::ALL_ONES, 0xffff);
+/// assert_eq!(u16::SIGN_BIT, 0x8000);
+/// assert_eq!(i16::SIGN_BIT, i16::MIN);
+/// ```
+pub trait BitMasks: PrimInt {
+    /// The low half of the bits set, e.g. `0x0000_ffff` for 32-bit integers.
+    const LOW_HALF: Self;
+
+    /// The high half of the bits set, e.g. `0xffff_0000` for 32-bit integers.
+    const HIGH_HALF: Self;
+
+    /// All bits set, e.g. `0xffff_ffff` for 32-bit integers.
+    const ALL_ONES: Self;
+
+    /// Only the most significant bit set, e.g. `0x8000_0000` for 32-bit
+    /// integers.
+    const SIGN_BIT: Self;
+}
+
+macro_rules! bit_masks_impl {
+    ($T:ty, $U:ty) => {
+        impl BitMasks for $T {
+            const LOW_HALF: $T = (<$U>::MAX >> (<$U>::BITS / 2)) as $T;
+            const HIGH_HALF: $T = !Self::LOW_HALF;
+            const ALL_ONES: $T = <$U>::MAX as $T;
+            const SIGN_BIT: $T = !(<$U>::MAX >> 1) as $T;
+        }
+    };
+}
+
+// bit_masks_impl!(type, unsigned);
+bit_masks_impl!(u8, u8);
+bit_masks_impl!(u16, u16);
+bit_masks_impl!(u32, u32);
+bit_masks_impl!(u64, u64);
+bit_masks_impl!(u128, u128);
+bit_masks_impl!(usize, usize);
+bit_masks_impl!(i8, u8);
+bit_masks_impl!(i16, u16);
+bit_masks_impl!(i32, u32);
+bit_masks_impl!(i64, u64);
+bit_masks_impl!(i128, u128);
+bit_masks_impl!(isize, usize);
+
+/// Returns `a` if `cond` is true, otherwise `b`, selecting between them with a
+/// bit mask rather than a branch on the values.
+///
+/// This only avoids an obvious data-dependent branch; the compiler is free to
+/// reintroduce one, so it is **not** a cryptographic constant-time guarantee.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::select;
+///
+/// assert_eq!(select(true, 3u8, 7u8), 3);
+/// assert_eq!(select(false, -3i64, 7i64), 7);
+/// ```
+#[inline]
+pub fn select<T: PrimInt>(cond: bool, a: T, b: T) -> T {
+    let mask = if cond { !T::zero() } else { T::zero() };
+    (a & mask) | (b & !mask)
+}
+
+/// Packs a slice of booleans into the low bits of an integer, with `bits[0]`
+/// in the least significant bit.
+///
+/// # Panics
+///
+/// Panics if `bits` is longer than the number of bits in `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::pack_bools;
+///
+/// assert_eq!(pack_bools::<u8>(&[true, false, true, true]), 0b1101);
+/// assert_eq!(pack_bools::<i8>(&[true; 8]), -1);
+/// ```
+pub fn pack_bools<T: PrimInt>(bits: &[bool]) -> T {
+    assert!(
+        bits.len() <= T::zero().count_zeros() as usize,
+        "too many booleans to pack into this type"
+    );
+    bits.iter().rev().fold(T::zero(), |acc, &bit| {
+        let acc = acc.unsigned_shl(1);
+        if bit {
+            acc | T::one()
+        } else {
+            acc
+        }
+    })
+}
+
+/// Unpacks the low `count` bits of an integer into booleans, with the least
+/// significant bit first. This is the inverse of [`pack_bools`].
+///
+/// Bits beyond the width of `T` are unpacked as `false`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::unpack_bools;
+///
+/// assert_eq!(unpack_bools(0b1101u8, 4), [true, false, true, true]);
+/// ```
+#[cfg(feature = "std")]
+pub fn unpack_bools<T: PrimInt>(value: T, count: u32) -> std::vec::Vec<bool> {
+    let bits = T::zero().count_zeros();
+    (0..count)
+        .map(|i| i < bits && !(value.unsigned_shr(i) & T::one()).is_zero())
+        .collect()
+}
+
+/// Returns the Jaccard similarity of two bit sets, `|a & b| / |a | b|`.
+///
+/// Two empty sets are considered identical, with a similarity of `1.0`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::jaccard_bits;
+///
+/// assert_eq!(jaccard_bits(0b1100u8, 0b0110u8), 1.0 / 3.0);
+/// assert_eq!(jaccard_bits(0u32, 0u32), 1.0);
+/// ```
+pub fn jaccard_bits<T: PrimInt>(a: T, b: T) -> f64 {
+    let union = (a | b).count_ones();
+    if union == 0 {
+        1.0
+    } else {
+        (a & b).count_ones() as f64 / union as f64
+    }
+}
+
+/// Computes `a * num / den`, rounded down, saturating at `T::max_value()`.
+///
+/// The product is computed at full precision, so the result is exact
+/// whenever it fits, even if `a * num` alone would overflow.
+///
+/// # Panics
+///
+/// Panics if `den` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::saturating_scale;
+///
+/// assert_eq!(saturating_scale(u8::MAX, 2, 1), 255);
+/// assert_eq!(saturating_scale(u8::MAX, 1, 2), 127);
+/// assert_eq!(saturating_scale(200u8, 200, 250), 160);
+/// ```
+pub fn saturating_scale<T: PrimInt + Unsigned>(a: T, num: T, den: T) -> T {
+    assert!(!den.is_zero(), "attempt to divide by zero");
+    // Unsigned primitives are at most 128 bits wide.
+    let (a, num, den) = (a.to_u128(), num.to_u128(), den.to_u128());
+    mul_div_u128(a.unwrap(), num.unwrap(), den.unwrap())
+        .and_then(T::from)
+        .unwrap_or_else(T::max_value)
+}
+
+/// Returns the dot product `Σ a[i] * b[i]`, or `None` if the slices have
+/// different lengths or any product or partial sum overflows.
+///
+/// The products are added in order, so a partial sum that overflows gives
+/// `None` even if later terms would bring the total back into range.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::checked_dot;
+///
+/// assert_eq!(checked_dot(&[1i8, 2, 3], &[4, 5, 6]), Some(32));
+/// assert_eq!(checked_dot(&[100i8, 1], &[2, 1]), None);
+/// assert_eq!(checked_dot(&[1i8, 2], &[3]), None);
+/// ```
+pub fn checked_dot<T: PrimInt>(a: &[T], b: &[T]) -> Option<T> {
+    if a.len() != b.len() {
+        return None;
+    }
+    a.iter()
+        .zip(b)
+        .try_fold(T::zero(), |sum, (x, y)| sum.checked_add(&x.checked_mul(y)?))
+}
+
+/// Returns `a * b / d` rounded down, or `None` if it doesn't fit in a `u128`.
+pub(crate) fn mul_div_u128(a: u128, b: u128, d: u128) -> Option<u128> {
+    if let Some(p) = a.checked_mul(b) {
+        return Some(p / d);
+    }
+
+    // Multiply into a 256-bit `(hi, lo)` from 64-bit halves.
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+    let low = a0 * b0;
+    let (mid, carry) = (a0 * b1).overflowing_add(a1 * b0);
+    let (lo, carry_lo) = low.overflowing_add(mid << 64);
+    let hi = a1 * b1 + (mid >> 64) + ((carry as u128) << 64) + carry_lo as u128;
+    if hi >= d {
+        return None;
+    }
+
+    // Long division, one bit at a time; the remainder stays below `d`.
+    let (mut rem, mut quot) = (hi, 0u128);
+    for i in (0..128).rev() {
+        let overflow = rem >> 127 != 0;
+        rem = rem << 1 | (lo >> i & 1);
+        quot <<= 1;
+        if overflow || rem >= d {
+            rem = rem.wrapping_sub(d);
+            quot |= 1;
+        }
+    }
+    Some(quot)
+}
+
+/// Adds the little-endian multi-limb number `b` into `a`, returning the final
+/// carry-out.
+///
+/// The least significant limb comes first. If `b` is shorter than `a`, the
+/// carry keeps propagating through the remaining limbs of `a`. The sum wraps
+/// modulo the width of `a` when the carry-out is `true`.
+///
+/// # Panics
+///
+/// Panics if `b` is longer than `a`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::add_limbs;
+///
+/// let mut a = [u8::MAX, u8::MAX, 1];
+/// assert!(!add_limbs(&mut a, &[1]));
+/// assert_eq!(a, [0, 0, 2]);
+/// ```
+pub fn add_limbs<T: PrimInt + Unsigned>(a: &mut [T], b: &[T]) -> bool {
+    assert!(b.len() <= a.len(), "`b` has more limbs than `a`");
+    let mut carry = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        if !carry && i >= b.len() {
+            break;
+        }
+        let y = b.get(i).copied().unwrap_or_else(T::zero);
+        let (sum, c1) = carrying_add_limb(*x, y);
+        let (sum, c2) = carrying_add_limb(sum, if carry { T::one() } else { T::zero() });
+        *x = sum;
+        carry = c1 || c2;
+    }
+    carry
+}
+
+/// Subtracts the little-endian multi-limb number `b` from `a`, returning the
+/// final borrow-out.
+///
+/// The least significant limb comes first. If `b` is shorter than `a`, the
+/// borrow keeps propagating through the remaining limbs of `a`. The difference
+/// wraps modulo the width of `a` when the borrow-out is `true`, that is, when
+/// `b` was greater than `a`.
+///
+/// # Panics
+///
+/// Panics if `b` is longer than `a`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::sub_limbs;
+///
+/// let mut a = [0u8, 0, 2];
+/// assert!(!sub_limbs(&mut a, &[1]));
+/// assert_eq!(a, [u8::MAX, u8::MAX, 1]);
+/// ```
+pub fn sub_limbs<T: PrimInt + Unsigned>(a: &mut [T], b: &[T]) -> bool {
+    assert!(b.len() <= a.len(), "`b` has more limbs than `a`");
+    let mut borrow = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        if !borrow && i >= b.len() {
+            break;
+        }
+        let y = b.get(i).copied().unwrap_or_else(T::zero);
+        let (diff, b1) = borrowing_sub_limb(*x, y);
+        let (diff, b2) = borrowing_sub_limb(diff, if borrow { T::one() } else { T::zero() });
+        *x = diff;
+        borrow = b1 || b2;
+    }
+    borrow
+}
+
+/// Multiplies the little-endian multi-limb numbers `a` and `b` into `out`.
+///
+/// The least significant limb comes first. `out` needs room for
+/// `a.len() + b.len()` limbs, which always holds the full product; any limbs
+/// beyond that are set to zero.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than `a.len() + b.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::mul_limbs;
+///
+/// // 0x1_ff * 0x2_00 = 0x3_fe_00
+/// let mut out = [0u8; 4];
+/// mul_limbs(&[0xff, 0x01], &[0x00, 0x02], &mut out);
+/// assert_eq!(out, [0x00, 0xfe, 0x03, 0x00]);
+/// ```
+pub fn mul_limbs<T: PrimInt + Unsigned>(a: &[T], b: &[T], out: &mut [T]) {
+    assert!(
+        out.len() >= a.len() + b.len(),
+        "`out` is too short for the product"
+    );
+    for x in out.iter_mut() {
+        *x = T::zero();
+    }
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = T::zero();
+        for (j, &y) in b.iter().enumerate() {
+            // `x * y + out + carry` never exceeds two limbs.
+            let (lo, hi) = widening_mul_limb(x, y);
+            let (lo, c1) = carrying_add_limb(lo, out[i + j]);
+            let (lo, c2) = carrying_add_limb(lo, carry);
+            out[i + j] = lo;
+            carry = hi + T::from(c1 as u8 + c2 as u8).unwrap();
+        }
+        out[i + b.len()] = carry;
+    }
+}
+
+/// Adds `a` and `b` in ones' complement arithmetic, folding any carry-out
+/// back into the lowest bit.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::ones_complement_add;
+///
+/// assert_eq!(ones_complement_add(0x1234u16, 0x0001), 0x1235);
+/// assert_eq!(ones_complement_add(0xfffeu16, 0x0003), 0x0002);
+/// ```
+pub fn ones_complement_add<T: PrimInt + Unsigned>(a: T, b: T) -> T {
+    match carrying_add_limb(a, b) {
+        // The wrapped sum is at most `MAX - 1` when there is a carry.
+        (sum, true) => sum + T::one(),
+        (sum, false) => sum,
+    }
+}
+
+/// Returns the ones' complement sum of `data` as big-endian 16-bit words, as
+/// used by the Internet checksum of RFC 1071.
+///
+/// An odd trailing byte is padded with a zero byte. The checksum itself is
+/// the complement of this sum.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::ones_complement_sum;
+///
+/// assert_eq!(ones_complement_sum(&[0x12, 0x34, 0xff, 0xff]), 0x1234);
+/// assert_eq!(!ones_complement_sum(&[0x00, 0x01, 0xf2]), 0x0dfe);
+/// ```
+pub fn ones_complement_sum(data: &[u8]) -> u16 {
+    data.chunks(2).fold(0, |sum, word| {
+        let hi = word[0];
+        let lo = word.get(1).copied().unwrap_or(0);
+        ones_complement_add(sum, u16::from_be_bytes([hi, lo]))
+    })
+}
+
+/// Returns `x + y` wrapped to the width of `T`, along with the carry-out.
+fn carrying_add_limb<T: PrimInt + Unsigned>(x: T, y: T) -> (T, bool) {
+    match x.checked_add(&y) {
+        Some(sum) => (sum, false),
+        // `x + y - 2^n`, rearranged so that no step overflows.
+        None => (x - (T::max_value() - y) - T::one(), true),
+    }
+}
+
+/// Returns `x - y` wrapped to the width of `T`, along with the borrow-out.
+fn borrowing_sub_limb<T: PrimInt + Unsigned>(x: T, y: T) -> (T, bool) {
+    match x.checked_sub(&y) {
+        Some(diff) => (diff, false),
+        // `x - y + 2^n`, rearranged so that no step overflows.
+        None => (T::max_value() - (y - x) + T::one(), true),
+    }
+}
+
+/// Returns the full product `x * y` as its low and high limbs.
+fn widening_mul_limb<T: PrimInt + Unsigned>(x: T, y: T) -> (T, T) {
+    // Multiply half-width pieces, whose products can't overflow.
+    let half = T::zero().count_zeros() / 2;
+    let mask = T::max_value() >> half as usize;
+    let (x0, x1) = (x & mask, x >> half as usize);
+    let (y0, y1) = (y & mask, y >> half as usize);
+    let (p00, p01, p10, p11) = (x0 * y0, x0 * y1, x1 * y0, x1 * y1);
+
+    let mid = (p00 >> half as usize) + (p01 & mask) + (p10 & mask);
+    let lo = (p00 & mask) | (mid << half as usize);
+    let hi = p11 + (p01 >> half as usize) + (p10 >> half as usize) + (mid >> half as usize);
+    (lo, hi)
+}
+
+/// Parses an integer in the given `radix`, distinguishing the reasons it may
+/// fail.
+///
+/// The string may start with `+`, or with `-` if `T` is signed, followed by
+/// at least one digit. Digits above 9 are the letters `a` to `z` in either
+/// case, as for `from_str_radix`.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::{parse_int_detailed, IntParseError};
+///
+/// assert_eq!(parse_int_detailed::<i8>("-80", 16), Ok(-128));
+/// assert_eq!(parse_int_detailed::<i8>("80", 16), Err(IntParseError::Overflow));
+/// assert_eq!(parse_int_detailed::<u8>("1x", 10), Err(IntParseError::InvalidDigit(1)));
+/// ```
+pub fn parse_int_detailed<T: PrimInt>(s: &str, radix: u32) -> Result<T, IntParseError> {
+    if !(2..=36).contains(&radix) {
+        return Err(IntParseError::InvalidRadix);
+    }
+    let signed = T::min_value() < T::zero();
+    let (negative, start) = match s.as_bytes().first() {
+        Some(b'+') => (false, 1),
+        Some(b'-') if signed => (true, 1),
+        _ => (false, 0),
+    };
+    if s.len() == start {
+        return Err(IntParseError::Empty);
+    }
+
+    // If `radix` doesn't fit in `T`, then neither does any multiple of it.
+    let base = T::from(radix);
+    let mut acc = T::zero();
+    for (i, c) in s[start..].char_indices() {
+        let digit = c
+            .to_digit(radix)
+            .ok_or(IntParseError::InvalidDigit(start + i))?;
+        let digit = T::from(digit).ok_or(IntParseError::Overflow)?;
+        let shifted = if acc.is_zero() {
+            Some(acc)
+        } else {
+            base.and_then(|base| acc.checked_mul(&base))
+        };
+        // Negative values accumulate downward, so that `MIN` can be reached.
+        acc = shifted
+            .and_then(|acc| {
+                if negative {
+                    acc.checked_sub(&digit)
+                } else {
+                    acc.checked_add(&digit)
+                }
+            })
+            .ok_or(IntParseError::Overflow)?;
+    }
+    Ok(acc)
+}
+
+/// An error from [`parse_int_detailed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntParseError {
+    /// The string has no digits.
+    Empty,
+    /// The string has a character that is not a digit at this byte index.
+    InvalidDigit(usize),
+    /// The value is out of range for the integer type.
+    Overflow,
+    /// The radix is not in the range from 2 to 36.
+    InvalidRadix,
+}
+
+impl fmt::Display for IntParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IntParseError::Empty => "cannot parse integer from empty string".fmt(f),
+            IntParseError::InvalidDigit(i) => write!(f, "invalid digit at index {}", i),
+            IntParseError::Overflow => "number out of range for the integer type".fmt(f),
+            IntParseError::InvalidRadix => "radix must be in the range 2 to 36".fmt(f),
+        }
+    }
+}
+
+/// Scrambles the bits of `x` with a xorshift-multiply finalizer, so that
+/// flipping any input bit flips about half of the output bits.
+///
+/// This is the `fmix32`/`fmix64` finalizer from MurmurHash3. Widths below 32
+/// bits use the 32-bit constants truncated to fit, and 128-bit integers use
+/// the 64-bit constants repeated in both halves. The result is a bijection on
+/// `T`, but it is not a cryptographic hash.
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::int::mix;
+///
+/// assert_eq!(mix(0u32), 0);
+/// assert_ne!(mix(1u32), mix(2u32));
+/// assert_eq!(mix(-1i64) as u64, mix(u64::MAX));
+/// ```
+pub fn mix<T: PrimInt + WrappingMul>(x: T) -> T {
+    let bits = T::zero().count_zeros();
+    let (c1, c2, s1, s2, s3) = if bits <= 32 {
+        (0x85eb_ca6b, 0xc2b2_ae35, bits / 2, bits * 13 / 32, bits / 2)
+    } else {
+        let s = bits / 2 + 1;
+        (0xff51_afd7_ed55_8ccd, 0xc4ce_b9fe_1a85_ec53, s, s, s)
+    };
+    let c1: T = repeat_bits(c1);
+    let c2: T = repeat_bits(c2);
+
+    let mut x = x;
+    x = x ^ x.unsigned_shr(s1);
+    x = x.wrapping_mul(&c1);
+    x = x ^ x.unsigned_shr(s2);
+    x = x.wrapping_mul(&c2);
+    x ^ x.unsigned_shr(s3)
+}
+
+/// Fills `T` with copies of the low bits of `pattern`, truncating it when `T`
+/// is narrower.
+fn repeat_bits<T: PrimInt>(pattern: u64) -> T {
+    let mut ret = T::zero();
+    for i in 0..T::zero().count_zeros() {
+        if pattern >> (i % 64) & 1 == 1 {
+            ret = ret | T::one().unsigned_shl(i);
+        }
+    }
+    ret
+}
+
+fn one_per_byte<P: PrimInt>() -> P {
+    // i8, u8: return 0x01
+    // i16, u16: return 0x0101 = (0x01 << 8) | 0x01
+    // i32, u32: return 0x01010101 = (0x0101 << 16) | 0x0101
+    // ...
+    let mut ret = P::one();
+    let mut shift = 8;
+    let mut b = ret.count_zeros() >> 3;
+    while b != 0 {
+        ret = (ret << shift) | ret;
+        shift <<= 1;
+        b >>= 1;
+    }
+    ret
+}
+
+fn reverse_bits_fallback<P: PrimInt>(i: P) -> P {
+    let rep_01: P = one_per_byte();
+    let rep_03 = (rep_01 << 1) | rep_01;
+    let rep_05 = (rep_01 << 2) | rep_01;
+    let rep_0f = (rep_03 << 2) | rep_03;
+    let rep_33 = (rep_03 << 4) | rep_03;
+    let rep_55 = (rep_05 << 4) | rep_05;
+
+    // code above only used to determine rep_0f, rep_33, rep_55;
+    // optimizer should be able to do it in compile time
+    let mut ret = i.swap_bytes();
+    ret = ((ret & rep_0f) << 4) | ((ret >> 4) & rep_0f);
+    ret = ((ret & rep_33) << 2) | ((ret >> 2) & rep_33);
+    ret = ((ret & rep_55) << 1) | ((ret >> 1) & rep_55);
+    ret
+}
+
+macro_rules! prim_int_impl {
+    ($T:ty, $S:ty, $U:ty) => {
+        impl PrimInt for $T {
+            #[inline]
+            fn count_ones(self) -> u32 {
+                <$T>::count_ones(self)
+            }
+
+            #[inline]
+            fn count_zeros(self) -> u32 {
+                <$T>::count_zeros(self)
+            }
+
+            #[inline]
+            fn leading_ones(self) -> u32 {
+                <$T>::leading_ones(self)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$T>::leading_zeros(self)
+            }
+
+            #[inline]
+            fn trailing_ones(self) -> u32 {
+                <$T>::trailing_ones(self)
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                <$T>::trailing_zeros(self)
+            }
+
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                <$T>::rotate_left(self, n)
+            }
+
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                <$T>::rotate_right(self, n)
+            }
+
+            #[inline]
+            fn signed_shl(self, n: u32) -> Self {
+                ((self as $S) << n) as $T
+            }
+
+            #[inline]
+            fn signed_shr(self, n: u32) -> Self {
+                ((self as $S) >> n) as $T
+            }
+
+            #[inline]
+            fn unsigned_shl(self, n: u32) -> Self {
+                ((self as $U) << n) as $T
+            }
+
+            #[inline]
+            fn unsigned_shr(self, n: u32) -> Self {
+                ((self as $U) >> n) as $T
+            }
+
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                <$T>::swap_bytes(self)
+            }
+
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                <$T>::reverse_bits(self)
+            }
+
+            #[inline]
+            fn from_be(x: Self) -> Self {
+                <$T>::from_be(x)
+            }
+
+            #[inline]
+            fn from_le(x: Self) -> Self {
+                <$T>::from_le(x)
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                <$T>::to_be(self)
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                <$T>::to_le(self)
+            }
+
+            #[inline]
+            fn pow(self, exp: u32) -> Self {
                 <$T>::pow(self, exp)
             }
         }
@@ -506,6 +1773,39 @@ prim_int_impl!(i64, i64, u64);
 prim_int_impl!(i128, i128, u128);
 prim_int_impl!(isize, isize, usize);
 
+macro_rules! signedness_impl {
+    ($T:ty, $S:ty, $U:ty) => {
+        impl Signedness for $T {
+            type Signed = $S;
+            type Unsigned = $U;
+
+            #[inline]
+            fn cast_signed(self) -> $S {
+                self as $S
+            }
+
+            #[inline]
+            fn cast_unsigned(self) -> $U {
+                self as $U
+            }
+        }
+    };
+}
+
+// signedness_impl!(type, signed, unsigned);
+signedness_impl!(u8, i8, u8);
+signedness_impl!(u16, i16, u16);
+signedness_impl!(u32, i32, u32);
+signedness_impl!(u64, i64, u64);
+signedness_impl!(u128, i128, u128);
+signedness_impl!(usize, isize, usize);
+signedness_impl!(i8, i8, u8);
+signedness_impl!(i16, i16, u16);
+signedness_impl!(i32, i32, u32);
+signedness_impl!(i64, i64, u64);
+signedness_impl!(i128, i128, u128);
+signedness_impl!(isize, isize, usize);
+
 #[cfg(test)]
 mod tests {
     use crate::int::PrimInt;
@@ -559,4 +1859,782 @@ mod tests {
         assert_eq!(PrimInt::reverse_bits(-2i128), i128::MAX);
         assert_eq!(PrimInt::reverse_bits(i128::MAX), -2);
     }
+
+    #[test]
+    fn signedness_types() {
+        use crate::int::Signedness;
+
+        fn same<A: 'static, B: 'static>() -> bool {
+            core::any::TypeId::of::<A>() == core::any::TypeId::of::<B>()
+        }
+
+        macro_rules! check {
+            ($($T:ty => $S:ty, $U:ty;)+) => {$(
+                assert!(same::<<$T as Signedness>::Signed, $S>());
+                assert!(same::<<$T as Signedness>::Unsigned, $U>());
+            )+};
+        }
+
+        check! {
+            u8 => i8, u8;
+            u16 => i16, u16;
+            u32 => i32, u32;
+            u64 => i64, u64;
+            u128 => i128, u128;
+            usize => isize, usize;
+            i8 => i8, u8;
+            i16 => i16, u16;
+            i32 => i32, u32;
+            i64 => i64, u64;
+            i128 => i128, u128;
+            isize => isize, usize;
+        }
+
+        // The associated types must also be nameable in generic signatures.
+        fn round_trip<T: Signedness>(x: T) -> T::Unsigned {
+            x.cast_signed().cast_unsigned()
+        }
+        assert_eq!(round_trip(0x80u8), 0x80u8);
+        assert_eq!(round_trip(i32::MIN), 0x8000_0000u32);
+    }
+
+    #[test]
+    fn checked_succ_pred() {
+        macro_rules! check {
+            ($($T:ident)+) => {$(
+                assert_eq!(PrimInt::checked_succ($T::MAX), None);
+                assert_eq!(PrimInt::checked_succ($T::MAX - 1), Some($T::MAX));
+                assert_eq!(PrimInt::checked_succ($T::MIN), Some($T::MIN + 1));
+                assert_eq!(PrimInt::checked_pred($T::MIN), None);
+                assert_eq!(PrimInt::checked_pred($T::MIN + 1), Some($T::MIN));
+                assert_eq!(PrimInt::checked_pred($T::MAX), Some($T::MAX - 1));
+            )+};
+        }
+
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    }
+
+    #[test]
+    fn select() {
+        use crate::int::select;
+
+        macro_rules! check {
+            ($($T:ident)+) => {$(
+                assert_eq!(select(true, $T::MAX, $T::MIN), $T::MAX);
+                assert_eq!(select(false, $T::MAX, $T::MIN), $T::MIN);
+                assert_eq!(select(true, 5 as $T, 9 as $T), 5);
+                assert_eq!(select(false, 5 as $T, 9 as $T), 9);
+            )+};
+        }
+
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    }
+
+    #[test]
+    fn mix_avalanche() {
+        use crate::int::mix;
+
+        macro_rules! check {
+            ($($T:ident)+) => {$({
+                let bits = $T::BITS;
+                let mut total = 0;
+                let mut samples = 0;
+                let mut x: $T = 0x0123_4567;
+                for _ in 0..64 {
+                    let h = mix(x);
+                    for i in 0..bits {
+                        let flipped = (mix(x ^ (1 << i)) ^ h).count_ones();
+                        assert!(flipped > bits / 8, "{}: mix({:#x}) bit {}", stringify!($T), x, i);
+                        total += flipped;
+                        samples += 1;
+                    }
+                    x = h.wrapping_add(0x9e37_79b9);
+                }
+                // On average, about half of the output bits should change.
+                let avg = total as f64 / samples as f64;
+                assert!((avg - bits as f64 / 2.0).abs() < bits as f64 / 16.0);
+            })+};
+        }
+
+        check!(u32 u64);
+    }
+
+    #[test]
+    fn checked_pow10() {
+        use crate::pow10;
+
+        assert_eq!(pow10::<u8>(2), Some(100));
+        assert_eq!(pow10::<u8>(3), None);
+        assert_eq!(pow10::<u32>(3), Some(1000));
+        assert_eq!(pow10::<i64>(18), Some(1_000_000_000_000_000_000));
+        assert_eq!(pow10::<i64>(19), None);
+        assert_eq!(pow10::<u128>(38), Some(10u128.pow(38)));
+        assert_eq!(pow10::<u128>(39), None);
+
+        assert_eq!(PrimInt::checked_pow10(-12i8, 1), Some(-120));
+        assert_eq!(PrimInt::checked_pow10(-13i8, 1), None);
+        assert_eq!(PrimInt::checked_pow10(7u16, 0), Some(7));
+    }
+
+    #[test]
+    fn truncate_and_sign_extend() {
+        assert_eq!(PrimInt::sign_extend_from_bits(0b1000u8, 4), 0b1111_1000u8);
+        assert_eq!(PrimInt::sign_extend_from_bits(0b1111_0111u8, 4), 0b0111u8);
+        assert_eq!(PrimInt::sign_extend_from_bits(0x8000u16, 16), 0x8000);
+        assert_eq!(PrimInt::sign_extend_from_bits(0x1_8000i32, 16), -0x8000);
+        assert_eq!(PrimInt::sign_extend_from_bits(1i64, 1), -1);
+        assert_eq!(PrimInt::sign_extend_from_bits(2i64, 1), 0);
+        assert_eq!(PrimInt::sign_extend_from_bits(u128::MAX, 0), 0);
+        assert_eq!(
+            PrimInt::sign_extend_from_bits(1u128 << 126, 127),
+            u128::MAX << 126
+        );
+
+        assert_eq!(PrimInt::truncate_to_bits(0xabcdu16, 8), 0xcd);
+        assert_eq!(PrimInt::truncate_to_bits(i8::MIN, 7), 0);
+        assert_eq!(PrimInt::truncate_to_bits(i8::MIN, 8), i8::MIN);
+        assert_eq!(PrimInt::truncate_to_bits(-1i128, 127), i128::MAX);
+        assert_eq!(PrimInt::truncate_to_bits(u32::MAX, 40), u32::MAX);
+
+        for x in 0..=255u8 {
+            for n in 1..8 {
+                let ext = PrimInt::sign_extend_from_bits(x, n);
+                assert_eq!(
+                    PrimInt::truncate_to_bits(ext, n),
+                    PrimInt::truncate_to_bits(x, n)
+                );
+                assert_eq!(
+                    (ext as i8) >> (n - 1),
+                    if x >> (n - 1) & 1 == 1 { -1 } else { 0 }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bit_masks() {
+        use crate::int::BitMasks;
+
+        assert_eq!(u8::LOW_HALF, 0x0f);
+        assert_eq!(u8::HIGH_HALF, 0xf0);
+        assert_eq!(u8::ALL_ONES, 0xff);
+        assert_eq!(u8::SIGN_BIT, 0x80);
+
+        assert_eq!(u32::LOW_HALF, 0x0000_ffff);
+        assert_eq!(u32::HIGH_HALF, 0xffff_0000);
+        assert_eq!(u32::ALL_ONES, 0xffff_ffff);
+        assert_eq!(u32::SIGN_BIT, 0x8000_0000);
+
+        assert_eq!(i8::LOW_HALF, 0x0f);
+        assert_eq!(i8::HIGH_HALF, -0x10);
+        assert_eq!(i8::ALL_ONES, -1);
+        assert_eq!(i8::SIGN_BIT, i8::MIN);
+
+        assert_eq!(u128::LOW_HALF, u64::MAX as u128);
+        assert_eq!(i64::HIGH_HALF as u64, 0xffff_ffff_0000_0000);
+        assert_eq!(usize::SIGN_BIT, !(usize::MAX >> 1));
+    }
+
+    #[test]
+    fn pack_bools() {
+        use crate::int::pack_bools;
+
+        let flags = [true, false, false, true, true, false, true];
+        let packed: u8 = pack_bools(&flags);
+        assert_eq!(packed, 0b101_1001);
+        assert_eq!(pack_bools::<u8>(&[]), 0);
+        assert_eq!(pack_bools::<i16>(&[false; 15]), 0);
+        assert_eq!(pack_bools::<u128>(&[true; 128]), u128::MAX);
+
+        #[cfg(feature = "std")]
+        {
+            use crate::int::unpack_bools;
+
+            assert_eq!(unpack_bools(packed, flags.len() as u32), flags);
+            assert_eq!(
+                unpack_bools(-1i8, 10)[..],
+                [true, true, true, true, true, true, true, true, false, false]
+            );
+            assert!(unpack_bools(0u64, 0).is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn pack_bools_too_many() {
+        let _: u8 = crate::int::pack_bools(&[false; 9]);
+    }
+
+    #[test]
+    fn jaccard_bits() {
+        use crate::int::jaccard_bits;
+
+        assert_eq!(jaccard_bits(0b1011u8, 0b1011u8), 1.0);
+        assert_eq!(jaccard_bits(-1i64, -1i64), 1.0);
+        assert_eq!(jaccard_bits(0b1010u16, 0b0101u16), 0.0);
+        assert_eq!(jaccard_bits(0u32, u32::MAX), 0.0);
+        assert_eq!(jaccard_bits(0u128, 0u128), 1.0);
+        assert_eq!(jaccard_bits(0b1111u8, 0b0011u8), 0.5);
+        assert_eq!(jaccard_bits(u64::MAX, u64::MAX >> 48), 0.25);
+        assert_eq!(jaccard_bits(i8::MIN, -1i8), 0.125);
+    }
+
+    #[test]
+    fn digits() {
+        fn check<T: PrimInt>(x: T, radix: u32, expected: &[u8]) {
+            assert!(x.digits(radix).eq(expected.iter().copied()));
+            assert!(x.digits_be(radix).eq(expected.iter().rev().copied()));
+            assert_eq!(x.digits(radix).len(), expected.len());
+        }
+
+        check(123u32, 10, &[3, 2, 1]);
+        check(-123i32, 10, &[3, 2, 1]);
+        check(0u8, 10, &[0]);
+        check(0i8, 2, &[0]);
+        check(9u8, 10, &[9]);
+        check(10u8, 10, &[0, 1]);
+        check(255u8, 16, &[15, 15]);
+        check(255u8, 2, &[1; 8]);
+        check(-128i8, 2, &[0, 0, 0, 0, 0, 0, 0, 1]);
+        check(-128i8, 10, &[8, 2, 1]);
+        check(127i8, 36, &[19, 3]);
+        check(i16::MIN, 8, &[0, 0, 0, 0, 0, 1]);
+        check(
+            u64::MAX,
+            10,
+            &[5, 1, 6, 1, 5, 5, 9, 0, 7, 3, 7, 0, 4, 4, 7, 6, 4, 4, 8, 1],
+        );
+        check(i128::MIN, 2, &{
+            let mut bits = [0; 128];
+            bits[127] = 1;
+            bits
+        });
+
+        // Both ends can be consumed from the same iterator.
+        let mut digits = 12345u32.digits(10);
+        assert_eq!(digits.next(), Some(5));
+        assert_eq!(digits.next_back(), Some(1));
+        assert_eq!(digits.next(), Some(4));
+        assert_eq!(digits.next_back(), Some(2));
+        assert_eq!(digits.len(), 1);
+        assert_eq!(digits.next_back(), Some(3));
+        assert_eq!(digits.next(), None);
+        assert_eq!(digits.next_back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digits_bad_radix() {
+        let _ = 10u8.digits(37);
+    }
+
+    #[test]
+    fn bit_reader() {
+        use super::BitReader;
+
+        // An IPv4 header word: version, header length, DSCP, ECN, length.
+        let mut header = BitReader::msb_first(0x4502_05dcu32);
+        assert_eq!(header.read_bits(4), 4);
+        assert_eq!(header.read_bits(4), 5);
+        assert_eq!(header.read_bits(6), 0);
+        assert_eq!(header.read_bits(2), 2);
+        assert_eq!(header.remaining(), 16);
+        assert_eq!(header.read_bits(16), 1500);
+        assert_eq!(header.remaining(), 0);
+        assert_eq!(header.read_bits(0), 0);
+
+        let mut fields = BitReader::lsb_first(0x4502_05dcu32);
+        assert_eq!(fields.read_bits(8), 0xdc);
+        assert_eq!(fields.read_bits(3), 0b101);
+        assert_eq!(fields.read_bits(21), 0x4502_05dc >> 11);
+        assert_eq!(fields.remaining(), 0);
+
+        let mut whole = BitReader::lsb_first(-2i8);
+        assert_eq!(whole.read_bits(8), -2);
+
+        let mut signed = BitReader::msb_first(-1i16);
+        assert_eq!(signed.read_bits(1), 1);
+        assert_eq!(signed.read_bits(3), 7);
+        assert_eq!(signed.read_bits(12), 0x0fff);
+
+        let mut signed = BitReader::lsb_first(i64::MIN);
+        assert_eq!(signed.read_bits(63), 0);
+        assert_eq!(signed.read_bits(1), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_reader_overrun() {
+        let mut reader = super::BitReader::msb_first(0u8);
+        reader.read_bits(5);
+        reader.read_bits(4);
+    }
+
+    #[test]
+    fn wrapping_distance() {
+        use crate::int::Signedness;
+
+        assert_eq!(Signedness::wrapping_distance(1u8, 255), 2);
+        assert_eq!(Signedness::wrapping_distance(255u8, 1), -2);
+        assert_eq!(Signedness::wrapping_distance(0u8, 255), 1);
+        assert_eq!(Signedness::wrapping_distance(255u8, 0), -1);
+        assert_eq!(Signedness::wrapping_distance(7u8, 7), 0);
+        assert_eq!(Signedness::wrapping_distance(127u8, 0), 127);
+        assert_eq!(Signedness::wrapping_distance(0u8, 127), -127);
+        assert_eq!(Signedness::wrapping_distance(128u8, 0), i8::MIN);
+        assert_eq!(Signedness::wrapping_distance(0u8, 128), i8::MIN);
+        assert_eq!(Signedness::wrapping_distance(0u8, 129), 127);
+
+        assert_eq!(Signedness::wrapping_distance(5u32, u32::MAX - 4), 10);
+        assert_eq!(Signedness::wrapping_distance(u32::MAX - 4, 5), -10);
+        assert_eq!(Signedness::wrapping_distance(0u128, u128::MAX), 1);
+        assert_eq!(Signedness::wrapping_distance(u64::MAX, 0), -1);
+
+        assert_eq!(Signedness::wrapping_distance(i8::MIN, i8::MAX), 1);
+        assert_eq!(Signedness::wrapping_distance(-3i16, 4), -7);
+    }
+
+    #[test]
+    fn saturating_scale() {
+        use super::saturating_scale;
+
+        assert_eq!(saturating_scale(u8::MAX, 2, 1), 255);
+        assert_eq!(saturating_scale(u8::MAX, 1, 2), 127);
+        assert_eq!(saturating_scale(u8::MAX, u8::MAX, u8::MAX), 255);
+        assert_eq!(saturating_scale(100u8, 3, 4), 75);
+        assert_eq!(saturating_scale(0u8, 255, 1), 0);
+        assert_eq!(saturating_scale(128u8, 2, 1), 255);
+        assert_eq!(saturating_scale(u32::MAX, u32::MAX, u32::MAX), u32::MAX);
+        assert_eq!(saturating_scale(u64::MAX, 3, 4), u64::MAX / 4 * 3 + 2);
+        assert_eq!(saturating_scale(u128::MAX, u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(saturating_scale(u128::MAX, 2, 3), u128::MAX / 3 * 2);
+        assert_eq!(saturating_scale(u128::MAX, 3, 2), u128::MAX);
+        assert_eq!(saturating_scale(1u128 << 100, 1 << 100, 1 << 90), 1 << 110);
+        assert_eq!(
+            saturating_scale(u128::MAX - 1, u128::MAX - 2, u128::MAX),
+            u128::MAX - 3
+        );
+    }
+
+    #[test]
+    fn checked_dot() {
+        use super::checked_dot;
+
+        assert_eq!(checked_dot::<i32>(&[], &[]), Some(0));
+        assert_eq!(checked_dot(&[3u8], &[5]), Some(15));
+        assert_eq!(checked_dot(&[-3i16, 7, 2], &[4, -1, 10]), Some(1));
+        assert_eq!(checked_dot(&[1u32, 2], &[1, 2, 3]), None);
+
+        // Each product fits, but the sum overflows partway through.
+        assert_eq!(checked_dot(&[100i8, 100, -100], &[1, 1, 1]), None);
+        assert_eq!(checked_dot(&[100i8, -100, 100], &[1, 1, 1]), Some(100));
+        assert_eq!(checked_dot(&[64i8, 64], &[1, 1]), None);
+        assert_eq!(checked_dot(&[64i8, 63], &[1, 1]), Some(127));
+
+        // A product overflows, or the last addition does.
+        assert_eq!(checked_dot(&[16u8, 1], &[16, 0]), None);
+        assert_eq!(checked_dot(&[i64::MIN, 1], &[-1, 0]), None);
+        assert_eq!(checked_dot(&[i64::MIN, 1], &[1, -1]), None);
+        assert_eq!(checked_dot(&[i64::MAX, 1], &[1, -1]), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn saturating_scale_by_zero() {
+        let _ = super::saturating_scale(1u16, 1, 0);
+    }
+
+    #[test]
+    fn add_limbs() {
+        use super::add_limbs;
+
+        // 0x0001_ffff_ffff + 0x0000_0000_0001 in 16-bit limbs.
+        let mut a = [0xffffu16, 0xffff, 0x0001];
+        assert!(!add_limbs(&mut a, &[0x0001, 0x0000, 0x0000]));
+        assert_eq!(a, [0x0000, 0x0000, 0x0002]);
+
+        // The carry propagates past the end of a shorter `b`.
+        let mut a = [u64::MAX, u64::MAX, 7];
+        assert!(!add_limbs(&mut a, &[1]));
+        assert_eq!(a, [0, 0, 8]);
+
+        // Adding 2^128 - 1 to itself in 64-bit limbs carries out.
+        let mut a = [u64::MAX, u64::MAX];
+        assert!(add_limbs(&mut a, &[u64::MAX, u64::MAX]));
+        assert_eq!(a, [u64::MAX - 1, u64::MAX]);
+
+        // Compare against native u128 addition split into u32 limbs.
+        let split = |x: u128| {
+            [
+                x as u32,
+                (x >> 32) as u32,
+                (x >> 64) as u32,
+                (x >> 96) as u32,
+            ]
+        };
+        for &(x, y) in &[
+            (0u128, 0u128),
+            (u128::MAX, 1),
+            (
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+                0xffff_0000_ffff_0000_ffff_0000_ffff_0000,
+            ),
+            (u128::MAX / 3, u128::MAX / 3 * 2),
+        ] {
+            let (sum, carry) = x.overflowing_add(y);
+            let mut a = split(x);
+            assert_eq!(add_limbs(&mut a, &split(y)), carry);
+            assert_eq!(a, split(sum));
+        }
+
+        let mut a: [u8; 0] = [];
+        assert!(!add_limbs(&mut a, &[]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_limbs_longer_addend() {
+        let _ = super::add_limbs(&mut [0u8], &[0, 0]);
+    }
+
+    #[test]
+    fn sub_limbs() {
+        use super::{add_limbs, sub_limbs};
+
+        // 0x0002_0000_0000 - 0x0000_0000_0001 in 16-bit limbs.
+        let mut a = [0x0000u16, 0x0000, 0x0002];
+        assert!(!sub_limbs(&mut a, &[0x0001, 0x0000, 0x0000]));
+        assert_eq!(a, [0xffff, 0xffff, 0x0001]);
+
+        // The borrow propagates past the end of a shorter `b`.
+        let mut a = [0u64, 0, 8];
+        assert!(!sub_limbs(&mut a, &[1]));
+        assert_eq!(a, [u64::MAX, u64::MAX, 7]);
+
+        // 0 - 1 underflows and wraps to all ones.
+        let mut a = [0u32; 3];
+        assert!(sub_limbs(&mut a, &[1]));
+        assert_eq!(a, [u32::MAX; 3]);
+
+        // Compare against native u128 subtraction split into u32 limbs.
+        let split = |x: u128| {
+            [
+                x as u32,
+                (x >> 32) as u32,
+                (x >> 64) as u32,
+                (x >> 96) as u32,
+            ]
+        };
+        for &(x, y) in &[
+            (0u128, 0u128),
+            (0, u128::MAX),
+            (1 << 64, 1),
+            (
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+                0xffff_0000_ffff_0000_ffff_0000_ffff_0000,
+            ),
+        ] {
+            let (diff, borrow) = x.overflowing_sub(y);
+            let mut a = split(x);
+            assert_eq!(sub_limbs(&mut a, &split(y)), borrow);
+            assert_eq!(a, split(diff));
+
+            // Adding `y` back restores `x`, carrying out exactly when we borrowed.
+            assert_eq!(add_limbs(&mut a, &split(y)), borrow);
+            assert_eq!(a, split(x));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_limbs_longer_subtrahend() {
+        let _ = super::sub_limbs(&mut [0u8], &[0, 0]);
+    }
+
+    #[test]
+    fn ones_complement() {
+        use super::{ones_complement_add, ones_complement_sum};
+
+        assert_eq!(ones_complement_add(0u8, 0), 0);
+        assert_eq!(ones_complement_add(0xffu8, 0x01), 0x01);
+        assert_eq!(ones_complement_add(0xffu8, 0xff), 0xff);
+        assert_eq!(ones_complement_add(0x8000_0000u32, 0x8000_0000), 1);
+        assert_eq!(ones_complement_add(u128::MAX - 1, 1), u128::MAX);
+
+        // An IPv4 header, with the checksum field at bytes 10 and 11.
+        let mut header = [
+            0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8,
+            0x00, 0x01, 0xc0, 0xa8, 0x00, 0xc7,
+        ];
+        let checksum = !ones_complement_sum(&header);
+        assert_eq!(checksum, 0xb861);
+
+        // Summing a header with its checksum filled in gives all ones.
+        header[10..12].copy_from_slice(&checksum.to_be_bytes());
+        assert_eq!(ones_complement_sum(&header), 0xffff);
+
+        // The RFC 1071 example, and its odd-length padding.
+        let data = [0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7];
+        assert_eq!(ones_complement_sum(&data), 0xddf2);
+        assert_eq!(ones_complement_sum(&data[..7]), 0xdcfb);
+        assert_eq!(ones_complement_sum(&[]), 0);
+    }
+
+    #[test]
+    fn mul_limbs() {
+        use super::mul_limbs;
+
+        // Compare against native u128 multiplication split into u16 limbs.
+        let split = |x: u128| {
+            let mut limbs = [0u16; 8];
+            for (i, limb) in limbs.iter_mut().enumerate() {
+                *limb = (x >> (16 * i)) as u16;
+            }
+            limbs
+        };
+        for &(x, y) in &[
+            (0u64, 0u64),
+            (1, u64::MAX),
+            (u64::MAX, u64::MAX),
+            (0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210),
+            (0xffff_0000_ffff, 0x1_0001),
+        ] {
+            let mut out = [0u16; 8];
+            mul_limbs(&split(x.into())[..4], &split(y.into())[..4], &mut out);
+            assert_eq!(out, split(u128::from(x) * u128::from(y)));
+        }
+
+        // Single 64-bit limbs exercise the widening multiply directly.
+        let mut out = [0u64; 2];
+        mul_limbs(&[u64::MAX], &[u64::MAX], &mut out);
+        assert_eq!(out, [1, u64::MAX - 1]);
+
+        // Uneven lengths, with extra output limbs cleared.
+        let mut out = [u32::MAX; 5];
+        mul_limbs(&[u32::MAX, u32::MAX], &[2], &mut out);
+        assert_eq!(out, [u32::MAX - 1, u32::MAX, 1, 0, 0]);
+
+        let mut out = [7u8; 2];
+        mul_limbs(&[], &[3, 4], &mut out);
+        assert_eq!(out, [0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_limbs_short_output() {
+        super::mul_limbs::<u8>(&[], &[1, 2], &mut [0]);
+    }
+
+    #[test]
+    fn bit_reversed_indices() {
+        use super::bit_reversed_indices;
+
+        assert!(bit_reversed_indices(3).eq([0, 4, 2, 6, 1, 5, 3, 7]));
+        assert!(bit_reversed_indices(3).rev().eq([7, 3, 5, 1, 6, 2, 4, 0]));
+        assert!(bit_reversed_indices(1).eq([0, 1]));
+        assert!(bit_reversed_indices(0).eq([0]));
+        assert_eq!(bit_reversed_indices(10).len(), 1024);
+
+        let mut seen = [false; 256];
+        for (i, j) in bit_reversed_indices(8).enumerate() {
+            assert_eq!(j, (i as u8).reverse_bits() as usize);
+            seen[j] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let last = usize::BITS - 1;
+        let mut big = bit_reversed_indices(last);
+        assert_eq!(big.next(), Some(0));
+        assert_eq!(big.next(), Some(1 << (last - 1)));
+        assert_eq!(big.next_back(), Some((1 << last) - 1));
+    }
+
+    #[test]
+    fn saturating_to_unsigned() {
+        use crate::int::Signedness;
+
+        assert_eq!((-5i32).saturating_to_unsigned::<u8>(), 0);
+        assert_eq!(1000i32.saturating_to_unsigned::<u8>(), 255);
+        assert_eq!(255i32.saturating_to_unsigned::<u8>(), 255);
+        assert_eq!(0i32.saturating_to_unsigned::<u8>(), 0);
+        assert_eq!(i128::MIN.saturating_to_unsigned::<u128>(), 0);
+        assert_eq!(
+            i128::MAX.saturating_to_unsigned::<u128>(),
+            i128::MAX as u128
+        );
+        assert_eq!(i64::MAX.saturating_to_unsigned::<u32>(), u32::MAX);
+        assert_eq!((-1i8).saturating_to_unsigned::<u64>(), 0);
+        assert_eq!(100i8.saturating_to_unsigned::<u64>(), 100);
+        assert_eq!(u64::MAX.saturating_to_unsigned::<u16>(), u16::MAX);
+        assert_eq!(7u64.saturating_to_unsigned::<u16>(), 7);
+    }
+
+    #[test]
+    fn saturating_abs_diff() {
+        use crate::int::Signedness;
+
+        macro_rules! test_abs_diff {
+            ($($t:ident)+) => {$(
+                for &a in &[$t::MIN, $t::MIN + 1, 0, 1, $t::MAX - 1, $t::MAX] {
+                    for &b in &[$t::MIN, $t::MIN + 1, 0, 1, $t::MAX - 1, $t::MAX] {
+                        assert_eq!(Signedness::saturating_abs_diff(&a, &b), a.abs_diff(b));
+                    }
+                }
+            )+};
+        }
+
+        test_abs_diff!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+        assert_eq!(Signedness::saturating_abs_diff(&i8::MIN, &i8::MAX), u8::MAX);
+        assert_eq!(
+            Signedness::saturating_abs_diff(&i64::MAX, &i64::MIN),
+            u64::MAX
+        );
+        assert_eq!(Signedness::saturating_abs_diff(&0u32, &u32::MAX), u32::MAX);
+        assert_eq!(Signedness::saturating_abs_diff(&-5i32, &-5), 0);
+    }
+
+    #[test]
+    fn parse_int_detailed() {
+        use super::{parse_int_detailed as parse, IntParseError::*};
+
+        assert_eq!(parse::<u8>("255", 10), Ok(255));
+        assert_eq!(parse::<u8>("+0", 10), Ok(0));
+        assert_eq!(parse::<u8>("000255", 10), Ok(255));
+        assert_eq!(parse::<i8>("-128", 10), Ok(-128));
+        assert_eq!(parse::<i8>("127", 10), Ok(127));
+        assert_eq!(parse::<i32>("-zz", 36), Ok(-1295));
+        assert_eq!(parse::<u16>("FfFf", 16), Ok(u16::MAX));
+        assert_eq!(parse::<u8>("11111111", 2), Ok(255));
+        assert_eq!(parse::<u8>("3", 36), Ok(3));
+        assert_eq!(parse::<u8>("0", 36), Ok(0));
+        assert_eq!(
+            parse::<i128>("-170141183460469231731687303715884105728", 10),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            parse::<u128>("340282366920938463463374607431768211455", 10),
+            Ok(u128::MAX)
+        );
+
+        assert_eq!(parse::<u8>("", 10), Err(Empty));
+        assert_eq!(parse::<i8>("-", 10), Err(Empty));
+        assert_eq!(parse::<u8>("+", 10), Err(Empty));
+
+        assert_eq!(parse::<u8>("12a", 10), Err(InvalidDigit(2)));
+        assert_eq!(parse::<u8>("-1", 10), Err(InvalidDigit(0)));
+        assert_eq!(parse::<i8>("--1", 10), Err(InvalidDigit(1)));
+        assert_eq!(parse::<i8>("+-1", 10), Err(InvalidDigit(1)));
+        assert_eq!(parse::<u32>(" 1", 10), Err(InvalidDigit(0)));
+        assert_eq!(parse::<u32>("2", 2), Err(InvalidDigit(0)));
+        assert_eq!(parse::<u32>("1é", 10), Err(InvalidDigit(1)));
+
+        assert_eq!(parse::<u8>("256", 10), Err(Overflow));
+        assert_eq!(parse::<i8>("128", 10), Err(Overflow));
+        assert_eq!(parse::<i8>("-129", 10), Err(Overflow));
+        assert_eq!(parse::<u8>("100000000", 2), Err(Overflow));
+        assert_eq!(
+            parse::<u128>("340282366920938463463374607431768211456", 10),
+            Err(Overflow)
+        );
+        assert_eq!(parse::<i8>("z", 36), Ok(35));
+
+        assert_eq!(parse::<u8>("1", 1), Err(InvalidRadix));
+        assert_eq!(parse::<u8>("1", 37), Err(InvalidRadix));
+        assert_eq!(parse::<u8>("", 0), Err(InvalidRadix));
+    }
+
+    #[test]
+    fn write_decimal() {
+        let mut buf = [0u8; 40];
+        assert_eq!(PrimInt::write_decimal(0u8, &mut buf), Some("0"));
+        assert_eq!(PrimInt::write_decimal(-1i8, &mut buf), Some("-1"));
+        assert_eq!(PrimInt::write_decimal(i8::MIN, &mut buf), Some("-128"));
+        assert_eq!(
+            PrimInt::write_decimal(u64::MAX, &mut buf),
+            Some("18446744073709551615")
+        );
+        assert_eq!(
+            PrimInt::write_decimal(i128::MIN, &mut buf),
+            Some("-170141183460469231731687303715884105728")
+        );
+        assert_eq!(
+            PrimInt::write_decimal(u128::MAX, &mut buf),
+            Some("340282366920938463463374607431768211455")
+        );
+
+        let mut exact = [0u8; 5];
+        assert_eq!(PrimInt::write_decimal(12345u16, &mut exact), Some("12345"));
+        assert_eq!(PrimInt::write_decimal(-1234i16, &mut exact), Some("-1234"));
+        assert_eq!(PrimInt::write_decimal(7u16, &mut exact), Some("7"));
+        assert_eq!(exact, *b"71234");
+
+        let mut small = [b'x'; 3];
+        assert_eq!(PrimInt::write_decimal(1000u32, &mut small), None);
+        assert_eq!(PrimInt::write_decimal(-100i32, &mut small), None);
+        assert_eq!(small, *b"xxx");
+        assert_eq!(PrimInt::write_decimal(0u32, &mut []), None);
+    }
+
+    #[test]
+    fn leading_sign_bits() {
+        assert_eq!(PrimInt::leading_sign_bits(-1i8), 7);
+        assert_eq!(PrimInt::leading_sign_bits(0i8), 7);
+        assert_eq!(PrimInt::leading_sign_bits(i8::MIN), 0);
+        assert_eq!(PrimInt::leading_sign_bits(i8::MAX), 0);
+        assert_eq!(PrimInt::leading_sign_bits(-64i8), 1);
+        assert_eq!(PrimInt::leading_sign_bits(63i8), 1);
+        assert_eq!(PrimInt::leading_sign_bits(-1i128), 127);
+        assert_eq!(PrimInt::leading_sign_bits(1i64 << 40), 22);
+        assert_eq!(PrimInt::leading_sign_bits(-(1i64 << 40)), 23);
+        assert_eq!(PrimInt::leading_sign_bits(0u16), 15);
+        assert_eq!(PrimInt::leading_sign_bits(u16::MAX), 15);
+        assert_eq!(PrimInt::leading_sign_bits(0x3000u16), 1);
+    }
+
+    #[test]
+    fn digit_sum_and_checksum() {
+        assert_eq!(PrimInt::digit_sum(1234u32, 10), 10);
+        assert_eq!(PrimInt::digit_sum(0u8, 10), 0);
+        assert_eq!(PrimInt::digit_sum(i8::MIN, 10), 11);
+        assert_eq!(PrimInt::digit_sum(u64::MAX, 2), 64);
+        assert_eq!(PrimInt::digit_sum(u128::MAX, 10), 165);
+
+        // Valid Luhn numbers, including a common test card number.
+        for &n in &[0u64, 18, 79927398713, 4539578763621486, 4111111111111111] {
+            assert_eq!(PrimInt::checksum_mod(n, 10, 10), 0, "{}", n);
+        }
+        for &n in &[1u64, 79927398710, 4539578763621487] {
+            assert_ne!(PrimInt::checksum_mod(n, 10, 10), 0, "{}", n);
+        }
+        assert_eq!(PrimInt::checksum_mod(-18i32, 10, 10), 0);
+        assert_eq!(PrimInt::checksum_mod(0x1fu8, 16, 16), (15 + 2) % 16);
+        assert_eq!(PrimInt::checksum_mod(0x9fu8, 16, 7), (15 + (18 - 15)) % 7);
+    }
+
+    #[test]
+    fn positive_mod() {
+        assert_eq!(PrimInt::positive_mod(-1i32, 7), 6);
+        assert_eq!(PrimInt::positive_mod(-7i32, 7), 0);
+        assert_eq!(PrimInt::positive_mod(-8i32, 7), 6);
+        assert_eq!(PrimInt::positive_mod(13i32, 7), 6);
+        assert_eq!(PrimInt::positive_mod(i8::MIN, 3), 1);
+        assert_eq!(PrimInt::positive_mod(i8::MIN, i8::MAX), 126);
+        assert_eq!(PrimInt::positive_mod(i128::MIN, 2), 0);
+        assert_eq!(PrimInt::positive_mod(u64::MAX, 10), 5);
+
+        assert_eq!(PrimInt::positive_mod_u32(-1i32, 7), 6);
+        assert_eq!(PrimInt::positive_mod_u32(12u8, 5), 2);
+        assert_eq!(PrimInt::positive_mod_u32(i64::MIN, 10), 2);
+        assert_eq!(PrimInt::positive_mod_u32(u64::MAX, 10), 5);
+        assert_eq!(PrimInt::positive_mod_u32(i128::MIN, 3), 1);
+        assert_eq!(PrimInt::positive_mod_u32(u128::MAX, 1 << 31), (1 << 31) - 1);
+        assert_eq!(PrimInt::positive_mod_u32(u32::MAX, u32::MAX), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn positive_mod_negative_modulus() {
+        let _ = PrimInt::positive_mod(5i32, -3);
+    }
 }
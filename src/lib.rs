@@ -27,17 +27,18 @@ use core::num::Wrapping;
 use core::ops::{Add, Div, Mul, Rem, Sub};
 use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
-pub use crate::bounds::Bounded;
+pub use crate::bounds::{Bounded, ConstBounded};
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use crate::float::Float;
 pub use crate::float::FloatConst;
 // pub use real::{FloatCore, Real}; // NOTE: Don't do this, it breaks `use num_traits::*;`.
 pub use crate::cast::{cast, AsPrimitive, FromPrimitive, NumCast, ToPrimitive};
-pub use crate::identities::{one, zero, ConstOne, ConstZero, One, Zero};
-pub use crate::int::PrimInt;
+pub use crate::identities::{from_u8_const, one, zero, ConstOne, ConstZero, One, SmallConst, Zero};
+pub use crate::int::{BitMasks, PrimInt, Signedness};
 pub use crate::ops::bytes::{FromBytes, ToBytes};
 pub use crate::ops::checked::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
+    CheckedAdd, CheckedAddMixed, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl,
+    CheckedShr, CheckedSub,
 };
 pub use crate::ops::euclid::{CheckedEuclid, Euclid};
 pub use crate::ops::inv::Inv;
@@ -46,7 +47,9 @@ pub use crate::ops::saturating::{Saturating, SaturatingAdd, SaturatingMul, Satur
 pub use crate::ops::wrapping::{
     WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr, WrappingSub,
 };
-pub use crate::pow::{checked_pow, pow, Pow};
+pub use crate::pow::{
+    checked_binomial, checked_factorial, checked_pow, pow, pow10, CheckedPow, Pow,
+};
 pub use crate::sign::{abs, abs_sub, signum, Signed, Unsigned};
 
 #[macro_use]
@@ -54,6 +57,7 @@ mod macros;
 
 pub mod bounds;
 pub mod cast;
+pub mod fixed;
 pub mod float;
 pub mod identities;
 pub mod int;
@@ -446,6 +450,129 @@ pub fn clamp_max<T: PartialOrd>(input: T, max: T) -> T {
     }
 }
 
+/// A value bounded in magnitude, keeping its sign
+///
+///  If input is greater than max then this returns max.
+///  If input is less than -max then this returns -max.
+///  Otherwise this returns input, including `NAN`.
+///
+/// **Panics** in debug mode if `!(max >= 0)`.
+#[inline]
+pub fn clamp_magnitude<T: Signed + PartialOrd + Copy>(input: T, max: T) -> T {
+    debug_assert!(max >= T::zero(), "max must be non-negative");
+    if input > max {
+        max
+    } else if input < -max {
+        -max
+    } else {
+        input
+    }
+}
+
+/// The inverse of linear interpolation
+///
+///  Returns `t` such that `a + (b - a) * t == v`, i.e. `(v - a) / (b - a)`.
+///  This is `0` at `a` and `1` at `b`, and is not clamped to that range.
+///  Returns `None` if `a == b`.
+///
+///  This is meant for floating point types; integer division truncates `t`.
+#[inline]
+pub fn inv_lerp<T: Num + Copy>(a: T, b: T, v: T) -> Option<T> {
+    if a == b {
+        None
+    } else {
+        Some((v - a) / (b - a))
+    }
+}
+
+/// Linearly maps a value from one range to another
+///
+///  Returns the value that is at the same relative position in
+///  `out_min..out_max` as `v` is in `in_min..in_max`. The result is not
+///  clamped to the output range.
+///  Returns `None` if `in_min == in_max`.
+///
+///  This is meant for floating point types; see [`inv_lerp`].
+#[inline]
+pub fn remap<T: Num + Copy>(v: T, in_min: T, in_max: T, out_min: T, out_max: T) -> Option<T> {
+    inv_lerp(in_min, in_max, v).map(|t| out_min + (out_max - out_min) * t)
+}
+
+/// Finds an interval where a function changes sign
+///
+///  Evaluates `f` at `steps + 1` evenly spaced points from `lo` to `hi`, and
+///  returns the first pair of neighbouring points `(a, b)` where `f(a)` and
+///  `f(b)` have opposite signs, so that a continuous `f` has a root between
+///  them. If `f` is exactly zero at a point `x`, `(x, x)` is returned.
+///  Returns `None` if no sign change is found, including when `f` returns NaN.
+///
+///  A `steps` of zero is treated as one, checking only the end points. For
+///  integers the points in between are rounded toward `lo`, and may repeat
+///  when `steps` is more than the distance from `lo` to `hi`. Integer types
+///  whose values don't fit in an `i128` or `u128` give `None`.
+pub fn find_sign_change<T, F>(f: F, lo: T, hi: T, steps: usize) -> Option<(T, T)>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+    F: Fn(T) -> T,
+{
+    let steps = steps.max(1);
+    let zero = T::zero();
+    let (min, max) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+    let integral = (T::one() / (T::one() + T::one())).is_zero();
+    // Point `k` is `lo + (hi - lo) * k / steps`, computed from `lo` rather than
+    // the previous point and clamped to the range. Integer spans are measured
+    // in `u128`, where they can't overflow; float spans are divided first.
+    let point = |k: usize| -> Option<T> {
+        let x = if k == steps {
+            hi
+        } else if integral {
+            let offset = |span: u128| int::mul_div_u128(span, k as u128, steps as u128);
+            if let (Some(l), Some(h)) = (lo.to_i128(), hi.to_i128()) {
+                let d = offset(l.abs_diff(h))?;
+                let x = if l <= h {
+                    (l as u128).wrapping_add(d)
+                } else {
+                    (l as u128).wrapping_sub(d)
+                };
+                <T as NumCast>::from(x as i128)?
+            } else {
+                let (l, h) = (lo.to_u128()?, hi.to_u128()?);
+                let d = offset(l.abs_diff(h))?;
+                <T as NumCast>::from(if l <= h { l + d } else { l - d })?
+            }
+        } else {
+            let n = <T as NumCast>::from(steps)?;
+            lo + (hi / n - lo / n) * <T as NumCast>::from(k)?
+        };
+        Some(if x < min {
+            min
+        } else if x > max {
+            max
+        } else {
+            x
+        })
+    };
+
+    let mut a = lo;
+    let mut fa = f(a);
+    if fa == zero {
+        return Some((a, a));
+    }
+    for i in 1..=steps {
+        let b = point(i)?;
+        let fb = f(b);
+        if fb == zero {
+            return Some((b, b));
+        }
+        if (fa < zero && fb > zero) || (fa > zero && fb < zero) {
+            return Some((a, b));
+        }
+        a = b;
+        fa = fb;
+    }
+    None
+}
+
 #[test]
 fn clamp_test() {
     // Int test
@@ -505,6 +632,122 @@ fn clamp_max_nan_max() {
     clamp_max(0., ::core::f32::NAN);
 }
 
+#[test]
+fn inv_lerp_test() {
+    assert_eq!(inv_lerp(0.0, 10.0, 5.0), Some(0.5));
+    assert_eq!(inv_lerp(0.0, 10.0, 0.0), Some(0.0));
+    assert_eq!(inv_lerp(0.0, 10.0, 10.0), Some(1.0));
+    assert_eq!(inv_lerp(0.0, 10.0, 15.0), Some(1.5));
+    assert_eq!(inv_lerp(10.0f32, 0.0, 2.5), Some(0.75));
+    assert_eq!(inv_lerp(-2.0, 2.0, -3.0), Some(-0.25));
+    assert_eq!(inv_lerp(3.0, 3.0, 3.0), None);
+    assert_eq!(inv_lerp(3.0f32, 3.0, 1.0), None);
+    assert_eq!(inv_lerp(0, 4, 8), Some(2));
+    assert_eq!(inv_lerp(1, 1, 8), None);
+}
+
+#[test]
+fn remap_test() {
+    assert_eq!(remap(0.0, 0.0, 10.0, 0.0, 100.0), Some(0.0));
+    assert_eq!(remap(2.5, 0.0, 10.0, 0.0, 100.0), Some(25.0));
+    assert_eq!(remap(5.0, 0.0, 10.0, 0.0, 100.0), Some(50.0));
+    assert_eq!(remap(10.0, 0.0, 10.0, 0.0, 100.0), Some(100.0));
+    assert_eq!(remap(12.0, 0.0, 10.0, 0.0, 100.0), Some(120.0));
+    assert_eq!(remap(5.0f32, 0.0, 10.0, 100.0, 0.0), Some(50.0));
+    assert_eq!(remap(-1.0, -1.0, 1.0, 20.0, 40.0), Some(20.0));
+    assert_eq!(remap(1.0, 2.0, 2.0, 0.0, 100.0), None);
+}
+
+#[test]
+fn clamp_magnitude_test() {
+    assert_eq!(clamp_magnitude(-5.0, 3.0), -3.0);
+    assert_eq!(clamp_magnitude(5.0, 3.0), 3.0);
+    assert_eq!(clamp_magnitude(-2.5f32, 3.0), -2.5);
+    assert_eq!(clamp_magnitude(1.0, 0.0), 0.0);
+    assert_eq!(clamp_magnitude(::core::f64::NEG_INFINITY, 1.0), -1.0);
+    assert!(clamp_magnitude(::core::f32::NAN, 1.0).is_nan());
+
+    assert_eq!(clamp_magnitude(-100i8, 10), -10);
+    assert_eq!(clamp_magnitude(i8::MIN, i8::MAX), -i8::MAX);
+    assert_eq!(clamp_magnitude(7i64, 10), 7);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn clamp_magnitude_negative_max() {
+    clamp_magnitude(1, -1);
+}
+
+#[test]
+fn find_sign_change_test() {
+    let f = |x: f64| x * x - 2.0;
+    let (a, b) = find_sign_change(f, 0.0, 2.0, 10).unwrap();
+    assert!(a < b);
+    assert!(a <= ::core::f64::consts::SQRT_2 && ::core::f64::consts::SQRT_2 <= b);
+    assert!((b - a - 0.2).abs() < 1e-12);
+    assert!(f(a) < 0.0 && f(b) > 0.0);
+
+    let (a, b) = find_sign_change(f, 0.0, -2.0, 4).unwrap();
+    assert_eq!((a, b), (-1.0, -1.5));
+    assert_eq!(find_sign_change(f, 0.0, 2.0, 0), Some((0.0, 2.0)));
+    assert_eq!(find_sign_change(f, 0.0, 1.0, 10), None);
+    assert_eq!(find_sign_change(f, 2.0, 3.0, 10), None);
+    assert_eq!(
+        find_sign_change(|x: f64| x - 0.5, 0.0, 1.0, 4),
+        Some((0.5, 0.5))
+    );
+    assert_eq!(
+        find_sign_change(|_: f64| ::core::f64::NAN, 0.0, 1.0, 4),
+        None
+    );
+
+    assert_eq!(
+        find_sign_change(|x: i32| x * x - 10, 0, 10, 10),
+        Some((3, 4))
+    );
+    assert_eq!(find_sign_change(|x: i32| x - 5, 0, 10, 2), Some((5, 5)));
+
+    // Integer ranges and step counts near the limits of the type.
+    assert_eq!(find_sign_change(|x: u8| x, 1, 10, 300), None);
+    assert_eq!(find_sign_change(|_: u8| 1, 254, 255, 255), None);
+    assert_eq!(
+        find_sign_change(|_: u128| 1, u128::MAX - 1, u128::MAX, 300),
+        None
+    );
+    assert_eq!(find_sign_change(|x: i32| (x - 7).signum(), 4, 5, 5), None);
+    assert_eq!(find_sign_change(|x: i8| x - 3, 0, 10, 100), Some((3, 3)));
+    assert_eq!(
+        find_sign_change(|x: i8| x + 127, -128, -126, 10),
+        Some((-127, -127))
+    );
+    assert_eq!(
+        find_sign_change(|x: u8| if x < 200 { 0 } else { 1 }, 100, 255, 255),
+        Some((100, 100))
+    );
+    assert_eq!(
+        find_sign_change(|x: i32| x.signum() * (x - 5).signum(), 1, i32::MAX, 4),
+        Some((1, 536870912))
+    );
+    let f = |x: i32| if x < 1000 { -1 } else { 1 };
+    assert_eq!(
+        find_sign_change(f, i32::MIN, i32::MAX, 4),
+        Some((-1, 1073741823))
+    );
+    assert_eq!(
+        find_sign_change(f, i32::MAX, i32::MIN, 4),
+        Some((1073741824, 0))
+    );
+    assert_eq!(
+        find_sign_change(|x: i128| x.signum(), i128::MIN, i128::MAX, 2),
+        Some((-1, i128::MAX))
+    );
+    assert_eq!(
+        find_sign_change(|x: f64| x.signum(), f64::MIN, f64::MAX, 2),
+        Some((f64::MIN, 0.0))
+    );
+}
+
 #[test]
 fn from_str_radix_unwrap() {
     // The Result error must impl Debug to allow unwrap()
@@ -516,6 +759,23 @@ fn from_str_radix_unwrap() {
     assert_eq!(f, 0.0);
 }
 
+#[test]
+fn from_str_radix_float_non_decimal() {
+    assert_eq!(f64::from_str_radix("1010.1", 2).unwrap(), 10.5);
+    assert_eq!(f32::from_str_radix("-1010.1", 2).unwrap(), -10.5);
+    assert_eq!(f64::from_str_radix("17.4", 8).unwrap(), 15.5);
+    assert_eq!(f64::from_str_radix("ff.8", 16).unwrap(), 255.5);
+    assert_eq!(f64::from_str_radix("1.8p1", 16).unwrap(), 3.0);
+    assert_eq!(f32::from_str_radix("1p-2", 16).unwrap(), 0.25);
+
+    assert!(f64::from_str_radix("1.2.3", 10).is_err());
+    assert!(f64::from_str_radix("1.2.3", 16).is_err());
+    assert!(f64::from_str_radix("1.0.1", 2).is_err());
+    assert!(f64::from_str_radix("102", 2).is_err());
+    assert!(f64::from_str_radix("1e1", 2).is_err());
+    assert!(f64::from_str_radix("", 2).is_err());
+}
+
 #[test]
 fn from_str_radix_multi_byte_fail() {
     // Ensure parsing doesn't panic, even on invalid sign characters
@@ -0,0 +1,143 @@
+use crate::ops::checked::CheckedAdd;
+use crate::ops::saturating::SaturatingAdd;
+use crate::ops::wrapping::WrappingAdd;
+use crate::Zero;
+
+/// How an [`Accumulator`] handles a sum that overflows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Stop accumulating at the first overflow, and report no sum.
+    Checked,
+    /// Wrap around at the boundary of the type.
+    Wrapping,
+    /// Saturate at the numeric bounds of the type.
+    Saturating,
+}
+
+/// A running sum that handles overflow according to an [`OverflowPolicy`].
+///
+/// # Examples
+///
+/// ```
+/// use num_traits::ops::accumulate::{Accumulator, OverflowPolicy};
+///
+/// let mut acc = Accumulator::new(OverflowPolicy::Saturating);
+/// for x in [200u8, 50, 10] {
+///     acc.push(x);
+/// }
+/// assert_eq!(acc.sum(), Some(255));
+/// assert!(acc.overflowed());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Accumulator<T> {
+    sum: T,
+    policy: OverflowPolicy,
+    overflowed: bool,
+}
+
+impl<T> Accumulator<T>
+where
+    T: Clone + Zero + CheckedAdd + WrappingAdd + SaturatingAdd,
+{
+    /// Creates an empty accumulator, with a sum of zero.
+    #[inline]
+    pub fn new(policy: OverflowPolicy) -> Self {
+        Accumulator {
+            sum: T::zero(),
+            policy,
+            overflowed: false,
+        }
+    }
+
+    /// Adds `value` to the running sum.
+    ///
+    /// With [`OverflowPolicy::Checked`], values pushed after an overflow are
+    /// ignored.
+    pub fn push(&mut self, value: T) {
+        match self.sum.checked_add(&value) {
+            Some(sum) => {
+                if !(self.overflowed && self.policy == OverflowPolicy::Checked) {
+                    self.sum = sum;
+                }
+            }
+            None => {
+                match self.policy {
+                    OverflowPolicy::Checked => {}
+                    OverflowPolicy::Wrapping => self.sum = self.sum.wrapping_add(&value),
+                    OverflowPolicy::Saturating => self.sum = self.sum.saturating_add(&value),
+                }
+                self.overflowed = true;
+            }
+        }
+    }
+
+    /// Returns the current sum, or `None` if the policy is
+    /// [`OverflowPolicy::Checked`] and the sum has overflowed.
+    #[inline]
+    pub fn sum(&self) -> Option<T> {
+        if self.overflowed && self.policy == OverflowPolicy::Checked {
+            None
+        } else {
+            Some(self.sum.clone())
+        }
+    }
+
+    /// Returns `true` if any push has overflowed, regardless of the policy.
+    #[inline]
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Returns the overflow policy of this accumulator.
+    #[inline]
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accumulate(policy: OverflowPolicy, values: &[i8]) -> Accumulator<i8> {
+        let mut acc = Accumulator::new(policy);
+        for &x in values {
+            acc.push(x);
+        }
+        acc
+    }
+
+    #[test]
+    fn no_overflow() {
+        for &policy in &[
+            OverflowPolicy::Checked,
+            OverflowPolicy::Wrapping,
+            OverflowPolicy::Saturating,
+        ] {
+            let acc = accumulate(policy, &[100, 27, -128, -100]);
+            assert_eq!(acc.sum(), Some(-101));
+            assert!(!acc.overflowed());
+            assert_eq!(acc.policy(), policy);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let values = [100, 100, -50, 10];
+
+        let acc = accumulate(OverflowPolicy::Checked, &values);
+        assert_eq!(acc.sum(), None);
+        assert!(acc.overflowed());
+
+        let acc = accumulate(OverflowPolicy::Wrapping, &values);
+        assert_eq!(acc.sum(), Some(-96));
+        assert!(acc.overflowed());
+
+        let acc = accumulate(OverflowPolicy::Saturating, &values);
+        assert_eq!(acc.sum(), Some(87));
+        assert!(acc.overflowed());
+
+        let acc = accumulate(OverflowPolicy::Saturating, &[-100, -100, 1]);
+        assert_eq!(acc.sum(), Some(-127));
+    }
+}
@@ -2,6 +2,9 @@ use core::borrow::{Borrow, BorrowMut};
 use core::cmp::{Eq, Ord, PartialEq, PartialOrd};
 use core::fmt::Debug;
 use core::hash::Hash;
+use core::iter::FusedIterator;
+
+use crate::PrimInt;
 
 pub trait NumBytes:
     Debug
@@ -255,6 +258,240 @@ int_to_from_bytes_impl!(isize, 4);
 float_to_from_bytes_impl!(f32, 4);
 float_to_from_bytes_impl!(f64, 8);
 
+/// Big-endian sign-magnitude encoding of signed integers, as an alternative to
+/// the two's complement encoding of [`ToBytes`] and [`FromBytes`].
+///
+/// The most significant bit holds the sign and the remaining bits hold the
+/// magnitude. The pattern for negative zero, which sign-magnitude otherwise
+/// has no use for, stands for `MIN`, so every value round-trips.
+pub trait SignMagnitudeBytes: ToBytes + FromBytes {
+    /// Encodes `self` as a sign bit followed by its magnitude, in big-endian
+    /// byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::SignMagnitudeBytes;
+    ///
+    /// assert_eq!(5i16.to_sign_magnitude_be(), [0x00, 0x05]);
+    /// assert_eq!((-5i16).to_sign_magnitude_be(), [0x80, 0x05]);
+    /// assert_eq!(i8::MIN.to_sign_magnitude_be(), [0x80]);
+    /// ```
+    fn to_sign_magnitude_be(self) -> <Self as ToBytes>::Bytes;
+
+    /// Decodes a sign bit followed by a magnitude, in big-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::SignMagnitudeBytes;
+    ///
+    /// assert_eq!(i16::from_sign_magnitude_be(&[0x80, 0x05]), -5);
+    /// assert_eq!(i8::from_sign_magnitude_be(&[0xff]), -127);
+    /// assert_eq!(i8::from_sign_magnitude_be(&[0x80]), i8::MIN);
+    /// ```
+    fn from_sign_magnitude_be(bytes: &<Self as FromBytes>::Bytes) -> Self;
+}
+
+macro_rules! sign_magnitude_bytes_impl {
+    ($($S:ident $U:ident)+) => {$(
+        impl SignMagnitudeBytes for $S {
+            #[inline]
+            fn to_sign_magnitude_be(self) -> <Self as ToBytes>::Bytes {
+                let sign = if self < 0 { 1 << ($U::BITS - 1) } else { 0 };
+                // `MIN` has no room for its magnitude beside the sign bit, but
+                // its magnitude is exactly the sign bit, the negative zero pattern.
+                (self.unsigned_abs() | sign).to_be_bytes()
+            }
+
+            #[inline]
+            fn from_sign_magnitude_be(bytes: &<Self as FromBytes>::Bytes) -> Self {
+                let bits = $U::from_be_bytes(*bytes);
+                let magnitude = (bits & ($U::MAX >> 1)) as $S;
+                match (bits >> ($U::BITS - 1), magnitude) {
+                    (0, _) => magnitude,
+                    (_, 0) => $S::MIN,
+                    (_, _) => -magnitude,
+                }
+            }
+        }
+    )+};
+}
+
+sign_magnitude_bytes_impl!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize);
+
+/// An iterator over the bytes of a LEB128 encoded integer.
+///
+/// This `struct` is created by the [`to_leb128`] and [`to_sleb128`] methods on
+/// [`Leb128`]. See their documentation for more.
+///
+/// [`to_leb128`]: Leb128::to_leb128
+/// [`to_sleb128`]: Leb128::to_sleb128
+#[derive(Clone, Debug)]
+pub struct Leb128Bytes<T> {
+    value: T,
+    signed: bool,
+    done: bool,
+}
+
+impl<T: PrimInt> Iterator for Leb128Bytes<T> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.done {
+            return None;
+        }
+        let byte = (self.value & T::from(0x7f).unwrap()).to_u8().unwrap();
+        if self.signed {
+            // Arithmetic shift for signed types, logical for unsigned.
+            self.value = self.value >> 7;
+            let sign = byte & 0x40 != 0;
+            self.done = (self.value.is_zero() && !sign) || (self.value == !T::zero() && sign);
+        } else {
+            self.value = self.value.unsigned_shr(7);
+            self.done = self.value.is_zero();
+        }
+        Some(if self.done { byte } else { byte | 0x80 })
+    }
+}
+
+impl<T: PrimInt> FusedIterator for Leb128Bytes<T> {}
+
+/// Variable-length LEB128 encoding of integers, as used by DWARF and
+/// WebAssembly.
+///
+/// This is implemented for all types that implement [`PrimInt`].
+pub trait Leb128: PrimInt {
+    /// Encodes the bits of `self` as unsigned LEB128, using the minimal
+    /// number of bytes.
+    ///
+    /// Negative values are encoded as their two's complement bit pattern, so
+    /// `-1i8` is encoded the same as `255u8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::Leb128;
+    ///
+    /// assert!(0u32.to_leb128().eq([0x00]));
+    /// assert!(624485u32.to_leb128().eq([0xe5, 0x8e, 0x26]));
+    /// ```
+    fn to_leb128(self) -> Leb128Bytes<Self> {
+        Leb128Bytes {
+            value: self,
+            signed: false,
+            done: false,
+        }
+    }
+
+    /// Encodes `self` as signed LEB128, using the minimal number of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::Leb128;
+    ///
+    /// assert!((-1i32).to_sleb128().eq([0x7f]));
+    /// assert!((-123456i32).to_sleb128().eq([0xc0, 0xbb, 0x78]));
+    /// assert!(64u8.to_sleb128().eq([0xc0, 0x00]));
+    /// ```
+    fn to_sleb128(self) -> Leb128Bytes<Self> {
+        Leb128Bytes {
+            value: self,
+            signed: true,
+            done: false,
+        }
+    }
+
+    /// Decodes an unsigned LEB128 integer, consuming bytes from the iterator
+    /// up to and including the last byte of the encoding.
+    ///
+    /// Non-minimal encodings are accepted. `None` is returned if the bytes
+    /// end before the encoding does, or if the value does not fit in the bits
+    /// of `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::Leb128;
+    ///
+    /// let mut bytes = [0xe5, 0x8e, 0x26, 0x01].iter().copied();
+    /// assert_eq!(u32::from_leb128(&mut bytes), Some(624485));
+    /// assert_eq!(bytes.next(), Some(0x01));
+    ///
+    /// assert_eq!(u8::from_leb128(&mut [0x80, 0x02].iter().copied()), None);
+    /// assert_eq!(u8::from_leb128(&mut [0x80].iter().copied()), None);
+    /// ```
+    fn from_leb128<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<Self> {
+        leb128_decode(bytes, false)
+    }
+
+    /// Decodes a signed LEB128 integer, consuming bytes from the iterator up
+    /// to and including the last byte of the encoding.
+    ///
+    /// Non-minimal encodings are accepted. `None` is returned if the bytes
+    /// end before the encoding does, or if the value is out of range for
+    /// `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::bytes::Leb128;
+    ///
+    /// assert_eq!(i32::from_sleb128(&mut [0xc0, 0xbb, 0x78].iter().copied()), Some(-123456));
+    /// assert_eq!(i8::from_sleb128(&mut [0xff, 0x7e].iter().copied()), None);
+    /// assert_eq!(u8::from_sleb128(&mut [0x7f].iter().copied()), None);
+    /// ```
+    fn from_sleb128<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<Self> {
+        leb128_decode(bytes, true)
+    }
+}
+
+impl<T: PrimInt> Leb128 for T {}
+
+fn leb128_decode<T: PrimInt, I: Iterator<Item = u8>>(bytes: &mut I, signed: bool) -> Option<T> {
+    let bits = T::zero().count_zeros();
+    // Bits from this position up must all match the sign of the encoding.
+    let high = if signed && T::min_value() < T::zero() {
+        bits - 1
+    } else {
+        bits
+    };
+
+    let mut value = T::zero();
+    let mut shift = 0u32;
+    let (mut high_ones, mut high_zeros) = (false, false);
+    loop {
+        let byte = bytes.next()?;
+        let payload = byte & 0x7f;
+        if shift < bits {
+            value = value | T::from(payload).unwrap().unsigned_shl(shift);
+        }
+        if shift.saturating_add(7) > high {
+            let mask = 0x7f & (0x7f << high.saturating_sub(shift).min(7));
+            high_ones |= payload & mask != 0;
+            high_zeros |= payload & mask != mask;
+        }
+        shift = shift.saturating_add(7);
+
+        if byte & 0x80 == 0 {
+            let negative = signed && payload & 0x40 != 0;
+            let valid = if negative {
+                !high_zeros && high < bits
+            } else {
+                !high_ones
+            };
+            if !valid {
+                return None;
+            }
+            if negative && shift < bits {
+                value = value | (!T::zero()).unsigned_shl(shift);
+            }
+            return Some(value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,4 +551,104 @@ mod tests {
 
         check_to_from_bytes!(f32 f64);
     }
+
+    #[test]
+    fn sign_magnitude_round_trip() {
+        macro_rules! check {
+            ($($S:ident $U:ident)+) => {$(
+                let sign = 1 << ($U::BITS - 1);
+                for &x in &[0, 1, -1, 42, -42, $S::MAX, -$S::MAX, $S::MIN + 1, $S::MIN] {
+                    let bytes = x.to_sign_magnitude_be();
+                    let bits = $U::from_be_bytes(bytes);
+                    assert_eq!(bits & !sign, x.unsigned_abs() & !sign);
+                    assert_eq!(bits & sign != 0, x < 0);
+                    assert_eq!($S::from_sign_magnitude_be(&bytes), x);
+                }
+            )+};
+        }
+
+        check!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize);
+
+        assert_eq!((-1i32).to_sign_magnitude_be(), [0x80, 0, 0, 1]);
+        assert_eq!(i8::MIN.to_sign_magnitude_be(), [0x80]);
+        assert_eq!(i8::from_sign_magnitude_be(&[0x80]), i8::MIN);
+        assert_eq!(i8::from_sign_magnitude_be(&[0x81]), -1);
+        assert_eq!(i8::from_sign_magnitude_be(&[0x7f]), 127);
+    }
+
+    #[test]
+    fn leb128_round_trip() {
+        macro_rules! check {
+            ($($ty:ident)+) => {$(
+                let bits = $ty::BITS;
+                let mut values = [
+                    0,
+                    1,
+                    63,
+                    64,
+                    127,
+                    $ty::MIN,
+                    $ty::MAX,
+                    $ty::MIN + 1,
+                    $ty::MAX - 1,
+                    $ty::MAX / 3,
+                ];
+                for x in values.iter_mut() {
+                    for &x in &[*x, x.wrapping_neg(), !*x] {
+                        let len = x.to_leb128().count() as u32;
+                        let used = bits - x.leading_zeros();
+                        assert_eq!(len, core::cmp::max(1, (used + 6) / 7));
+                        assert_eq!($ty::from_leb128(&mut x.to_leb128()), Some(x));
+
+                        let len = x.to_sleb128().count() as u32;
+                        let used = if $ty::MIN != 0 && x >> (bits - 1) != 0 {
+                            bits - x.leading_ones() + 1
+                        } else {
+                            bits - x.leading_zeros() + 1
+                        };
+                        assert_eq!(len, (used + 6) / 7);
+                        assert_eq!($ty::from_sleb128(&mut x.to_sleb128()), Some(x));
+                    }
+                }
+            )+};
+        }
+
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    }
+
+    #[test]
+    fn leb128_decode_errors() {
+        fn leb<T: Leb128>(bytes: &[u8]) -> Option<T> {
+            T::from_leb128(&mut bytes.iter().copied())
+        }
+        fn sleb<T: Leb128>(bytes: &[u8]) -> Option<T> {
+            T::from_sleb128(&mut bytes.iter().copied())
+        }
+
+        assert_eq!(leb::<u8>(&[]), None);
+        assert_eq!(leb::<u8>(&[0xff]), None);
+        assert_eq!(leb::<u8>(&[0xff, 0x01]), Some(255));
+        assert_eq!(leb::<u8>(&[0xff, 0x03]), None);
+        assert_eq!(leb::<u8>(&[0x81, 0x80, 0x80, 0x00]), Some(1));
+        assert_eq!(leb::<u8>(&[0x81, 0x80, 0x80, 0x01]), None);
+        assert_eq!(leb::<i8>(&[0xff, 0x01]), Some(-1));
+        let mut max = [0xff; 10];
+        max[9] = 0x01;
+        assert_eq!(leb::<u64>(&max), Some(u64::MAX));
+        max[9] = 0x02;
+        assert_eq!(leb::<u64>(&max), None);
+
+        assert_eq!(sleb::<i8>(&[0x40]), Some(-64));
+        assert_eq!(sleb::<i8>(&[0x80, 0x7f]), Some(-128));
+        assert_eq!(sleb::<i8>(&[0xbf, 0x7f]), Some(-65));
+        assert_eq!(sleb::<i8>(&[0xff, 0x7e]), None);
+        assert_eq!(sleb::<i8>(&[0xff, 0x00]), Some(127));
+        assert_eq!(sleb::<i8>(&[0x80, 0x01]), None);
+        assert_eq!(sleb::<i8>(&[0xff, 0xff, 0x7f]), Some(-1));
+        assert_eq!(sleb::<i8>(&[0xff, 0xff, 0x3f]), None);
+        assert_eq!(sleb::<u8>(&[0xff, 0x01]), Some(255));
+        assert_eq!(sleb::<u8>(&[0xff, 0x03]), None);
+        assert_eq!(sleb::<u8>(&[0x40]), None);
+        assert_eq!(sleb::<u16>(&[0xff, 0x00]), Some(127));
+    }
 }
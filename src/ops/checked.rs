@@ -1,3 +1,4 @@
+use core::num::Wrapping;
 use core::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
 
 /// Performs addition, returning `None` if overflow occurred.
@@ -181,6 +182,13 @@ checked_impl_unary!(CheckedNeg, checked_neg, i64);
 checked_impl_unary!(CheckedNeg, checked_neg, isize);
 checked_impl_unary!(CheckedNeg, checked_neg, i128);
 
+impl<T: CheckedNeg> CheckedNeg for Wrapping<T> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        self.0.checked_neg().map(Wrapping)
+    }
+}
+
 /// Performs shift left, returning `None` on shifts larger than or equal to
 /// the type width.
 pub trait CheckedShl: Sized + Shl<u32, Output = Self> {
@@ -257,3 +265,204 @@ checked_shift_impl!(CheckedShr, checked_shr, i32);
 checked_shift_impl!(CheckedShr, checked_shr, i64);
 checked_shift_impl!(CheckedShr, checked_shr, isize);
 checked_shift_impl!(CheckedShr, checked_shr, i128);
+
+/// Returns `true` if `a + b` overflows.
+///
+/// This is the same as `a.checked_add(b).is_none()`, but states the intent.
+///
+/// ```
+/// use num_traits::ops::checked::would_overflow_add;
+///
+/// assert!(would_overflow_add(&u8::MAX, &1));
+/// assert!(!would_overflow_add(&i8::MIN, &i8::MAX));
+/// ```
+#[inline]
+pub fn would_overflow_add<T: CheckedAdd>(a: &T, b: &T) -> bool {
+    a.checked_add(b).is_none()
+}
+
+/// Returns `true` if `a - b` overflows.
+///
+/// This is the same as `a.checked_sub(b).is_none()`, but states the intent.
+///
+/// ```
+/// use num_traits::ops::checked::would_overflow_sub;
+///
+/// assert!(would_overflow_sub(&0u32, &1));
+/// assert!(!would_overflow_sub(&-1i32, &i32::MAX));
+/// ```
+#[inline]
+pub fn would_overflow_sub<T: CheckedSub>(a: &T, b: &T) -> bool {
+    a.checked_sub(b).is_none()
+}
+
+/// Returns `true` if `a * b` overflows.
+///
+/// This is the same as `a.checked_mul(b).is_none()`, but states the intent.
+///
+/// ```
+/// use num_traits::ops::checked::would_overflow_mul;
+///
+/// assert!(would_overflow_mul(&16u8, &16));
+/// assert!(!would_overflow_mul(&-16i8, &8));
+/// ```
+#[inline]
+pub fn would_overflow_mul<T: CheckedMul>(a: &T, b: &T) -> bool {
+    a.checked_mul(b).is_none()
+}
+
+/// Adds a signed offset to an unsigned value of a possibly different width,
+/// returning `None` if the result does not fit in `Self`.
+///
+/// This avoids casting the offset to `Self` first, which would either reject
+/// negative offsets or silently wrap them.
+pub trait CheckedAddMixed<Rhs>: Sized {
+    /// Adds `rhs` to `self`, returning `None` if the result is negative or
+    /// exceeds the maximum value of `Self`.
+    ///
+    /// ```
+    /// use num_traits::CheckedAddMixed;
+    ///
+    /// assert_eq!(10u64.checked_add_mixed(-3i32), Some(7));
+    /// assert_eq!(0u64.checked_add_mixed(-1i32), None);
+    /// assert_eq!(250u8.checked_add_mixed(5i64), Some(255));
+    /// assert_eq!(250u8.checked_add_mixed(6i64), None);
+    /// ```
+    fn checked_add_mixed(&self, rhs: Rhs) -> Option<Self>;
+}
+
+macro_rules! checked_add_mixed_impl {
+    ($t:ty; $($rhs:ty)*) => {$(
+        impl CheckedAddMixed<$rhs> for $t {
+            #[inline]
+            fn checked_add_mixed(&self, rhs: $rhs) -> Option<$t> {
+                let magnitude = <$t>::try_from(rhs.unsigned_abs()).ok()?;
+                if rhs < 0 {
+                    <$t>::checked_sub(*self, magnitude)
+                } else {
+                    <$t>::checked_add(*self, magnitude)
+                }
+            }
+        }
+    )*};
+}
+
+checked_add_mixed_impl!(u8; i8 i16 i32 i64 isize i128);
+checked_add_mixed_impl!(u16; i8 i16 i32 i64 isize i128);
+checked_add_mixed_impl!(u32; i8 i16 i32 i64 isize i128);
+checked_add_mixed_impl!(u64; i8 i16 i32 i64 isize i128);
+checked_add_mixed_impl!(usize; i8 i16 i32 i64 isize i128);
+checked_add_mixed_impl!(u128; i8 i16 i32 i64 isize i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_neg_wrapping() {
+        assert_eq!(Wrapping(5i32).checked_neg(), Some(Wrapping(-5)));
+        assert_eq!(Wrapping(-5i32).checked_neg(), Some(Wrapping(5)));
+        assert_eq!(Wrapping(i32::MAX).checked_neg(), Some(Wrapping(-i32::MAX)));
+        assert_eq!(Wrapping(i32::MIN).checked_neg(), None);
+        assert_eq!(Wrapping(i8::MIN).checked_neg(), None);
+        assert_eq!(Wrapping(i128::MIN).checked_neg(), None);
+
+        assert_eq!(Wrapping(0u32).checked_neg(), Some(Wrapping(0)));
+        assert_eq!(Wrapping(1u32).checked_neg(), None);
+        assert_eq!(Wrapping(u8::MAX).checked_neg(), None);
+        assert_eq!(Wrapping(1usize).checked_neg(), None);
+    }
+
+    #[test]
+    fn checked_add_mixed() {
+        assert_eq!(0u64.checked_add_mixed(-1i32), None);
+        assert_eq!(1u64.checked_add_mixed(-1i32), Some(0));
+        assert_eq!(u64::MAX.checked_add_mixed(1i8), None);
+        assert_eq!(u64::MAX.checked_add_mixed(-1i8), Some(u64::MAX - 1));
+        assert_eq!(0u64.checked_add_mixed(i128::MAX), None);
+        assert_eq!(u64::MAX.checked_add_mixed(i128::MIN), None);
+        assert_eq!(5u8.checked_add_mixed(-300i32), None);
+        assert_eq!(255u8.checked_add_mixed(-255i32), Some(0));
+        assert_eq!(0u8.checked_add_mixed(i8::MIN), None);
+        assert_eq!(200u8.checked_add_mixed(i8::MIN), Some(72));
+        assert_eq!(0u128.checked_add_mixed(i128::MAX), Some(i128::MAX as u128));
+        assert_eq!(
+            u128::MAX.checked_add_mixed(i128::MIN),
+            Some(i128::MAX as u128)
+        );
+    }
+
+    #[test]
+    fn would_overflow() {
+        macro_rules! check {
+            ($($T:ident)+) => {$(
+                assert!(would_overflow_add(&$T::MAX, &1));
+                assert!(!would_overflow_add(&$T::MAX, &0));
+                assert!(!would_overflow_add(&($T::MAX - 1), &1));
+                assert!(would_overflow_sub(&$T::MIN, &1));
+                assert!(!would_overflow_sub(&$T::MIN, &0));
+                assert!(!would_overflow_sub(&($T::MIN + 1), &1));
+                assert!(would_overflow_mul(&$T::MAX, &2));
+                assert!(!would_overflow_mul(&$T::MAX, &1));
+                assert!(!would_overflow_mul(&($T::MAX / 2), &2));
+            )+};
+        }
+
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+        assert!(would_overflow_add(&i8::MIN, &-1));
+        assert!(would_overflow_sub(&i8::MAX, &-1));
+        assert!(would_overflow_sub(&0i8, &i8::MIN));
+        assert!(would_overflow_mul(&i8::MIN, &-1));
+        assert!(!would_overflow_mul(&i8::MIN, &1));
+    }
+
+    #[test]
+    fn checked_128_bit() {
+        fn mul<T: CheckedMul>(a: T, b: T) -> Option<T> {
+            a.checked_mul(&b)
+        }
+
+        assert_eq!(mul(u128::MAX, 2), None);
+        assert_eq!(mul(u128::MAX, 1), Some(u128::MAX));
+        assert_eq!(mul(1u128 << 64, 1 << 63), Some(1 << 127));
+        assert_eq!(mul(1u128 << 64, 1 << 64), None);
+        assert_eq!(mul(i128::MIN, -1), None);
+        assert_eq!(mul(i128::MAX, -1), Some(-i128::MAX));
+
+        assert_eq!(CheckedAdd::checked_add(&u128::MAX, &1), None);
+        assert_eq!(CheckedSub::checked_sub(&0u128, &1), None);
+        assert_eq!(CheckedAdd::checked_add(&i128::MAX, &1), None);
+        assert_eq!(CheckedSub::checked_sub(&i128::MIN, &1), None);
+        assert_eq!(CheckedDiv::checked_div(&u128::MAX, &0), None);
+        assert_eq!(CheckedDiv::checked_div(&i128::MIN, &-1), None);
+    }
+
+    #[test]
+    fn checked_rem() {
+        macro_rules! check {
+            ($($T:ident)+) => {$(
+                assert_eq!(CheckedRem::checked_rem(&(10 as $T), &7), Some(3));
+                assert_eq!(CheckedRem::checked_rem(&$T::MAX, &1), Some(0));
+                assert_eq!(CheckedRem::checked_rem(&$T::MIN, &1), Some(0));
+                assert_eq!(CheckedRem::checked_rem(&$T::MAX, &0), None);
+                assert_eq!(CheckedRem::checked_rem(&(0 as $T), &0), None);
+                assert_eq!(CheckedDiv::checked_div(&$T::MAX, &0), None);
+            )+};
+        }
+
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+        macro_rules! check_signed {
+            ($($T:ident)+) => {$(
+                assert_eq!(CheckedRem::checked_rem(&(-10 as $T), &7), Some(-3));
+                assert_eq!(CheckedRem::checked_rem(&(10 as $T), &-7), Some(3));
+                assert_eq!(CheckedRem::checked_rem(&$T::MIN, &-1), None);
+                assert_eq!(CheckedDiv::checked_div(&$T::MIN, &-1), None);
+                assert_eq!(CheckedRem::checked_rem(&$T::MIN, &$T::MAX), Some(-1));
+            )+};
+        }
+
+        check_signed!(i8 i16 i32 i64 i128 isize);
+    }
+}
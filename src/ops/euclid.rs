@@ -1,3 +1,4 @@
+use core::num::Wrapping;
 use core::ops::{Div, Rem};
 
 pub trait Euclid: Sized + Div<Self, Output = Self> + Rem<Self, Output = Self> {
@@ -135,6 +136,27 @@ impl Euclid for f64 {
     }
 }
 
+// `MIN / -1` wraps for `Wrapping` integers, so their Euclidean division
+// wraps too, rather than panicking like the primitives.
+macro_rules! euclid_wrapping_impl {
+    ($($t:ty)*) => {$(
+        impl Euclid for Wrapping<$t> {
+            #[inline]
+            fn div_euclid(&self, v: &Self) -> Self {
+                Wrapping(self.0.wrapping_div_euclid(v.0))
+            }
+
+            #[inline]
+            fn rem_euclid(&self, v: &Self) -> Self {
+                Wrapping(self.0.wrapping_rem_euclid(v.0))
+            }
+        }
+    )*}
+}
+
+euclid_wrapping_impl!(isize i8 i16 i32 i64 i128);
+euclid_wrapping_impl!(usize u8 u16 u32 u64 u128);
+
 pub trait CheckedEuclid: Euclid {
     /// Performs euclid division, returning `None` on division by zero or if
     /// overflow occurred.
@@ -185,6 +207,88 @@ macro_rules! checked_euclid_forward_impl {
 checked_euclid_forward_impl!(isize i8 i16 i32 i64 i128);
 checked_euclid_forward_impl!(usize u8 u16 u32 u64 u128);
 
+// Floats don't overflow, but a zero divisor or a non-finite operand gives an
+// infinite or NaN result, so that is reported as `None` instead. This means
+// any NaN operand returns `None`.
+macro_rules! checked_euclid_float_impl {
+    ($($t:ty)*) => {$(
+        impl CheckedEuclid for $t {
+            #[inline]
+            fn checked_div_euclid(&self, v: &$t) -> Option<Self> {
+                let q = Euclid::div_euclid(self, v);
+                if *v == 0.0 || !crate::float::FloatCore::is_finite(q) {
+                    None
+                } else {
+                    Some(q)
+                }
+            }
+
+            #[inline]
+            fn checked_rem_euclid(&self, v: &$t) -> Option<Self> {
+                let r = Euclid::rem_euclid(self, v);
+                if *v == 0.0 || !crate::float::FloatCore::is_finite(r) {
+                    None
+                } else {
+                    Some(r)
+                }
+            }
+        }
+    )*}
+}
+
+checked_euclid_float_impl!(f32 f64);
+
+/// Truncated remainder, as computed by the `%` operator and C's `%`/`fmod`.
+///
+/// This complements [`Euclid::rem_euclid`], making the convention explicit in
+/// generic code that uses both.
+pub trait RemTrunc: Sized + Rem<Self, Output = Self> {
+    /// Calculates the remainder of `self / v` with the quotient rounded
+    /// toward zero, so the result has the same sign as `self`.
+    ///
+    /// For integers, `MIN.rem_trunc(&-1)` is `0` instead of overflowing, but
+    /// a zero divisor panics just like `%`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::ops::euclid::RemTrunc;
+    /// use num_traits::Euclid;
+    ///
+    /// assert_eq!(RemTrunc::rem_trunc(&-7, &3), -1);
+    /// assert_eq!(Euclid::rem_euclid(&-7, &3), 2);
+    /// assert_eq!(RemTrunc::rem_trunc(&7.5, &-2.0), 1.5);
+    /// ```
+    fn rem_trunc(&self, v: &Self) -> Self;
+}
+
+macro_rules! rem_trunc_int_impl {
+    ($($t:ty)*) => {$(
+        impl RemTrunc for $t {
+            #[inline]
+            fn rem_trunc(&self, v: &$t) -> Self {
+                <$t>::wrapping_rem(*self, *v)
+            }
+        }
+    )*}
+}
+
+rem_trunc_int_impl!(isize i8 i16 i32 i64 i128);
+rem_trunc_int_impl!(usize u8 u16 u32 u64 u128);
+
+macro_rules! rem_trunc_float_impl {
+    ($($t:ty)*) => {$(
+        impl RemTrunc for $t {
+            #[inline]
+            fn rem_trunc(&self, v: &$t) -> Self {
+                *self % *v
+            }
+        }
+    )*}
+}
+
+rem_trunc_float_impl!(f32 f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,6 +363,49 @@ mod tests {
         test_euclid!(f32 f64);
     }
 
+    #[test]
+    fn euclid_wrapping() {
+        macro_rules! test_euclid {
+            ($($t:ident)+) => {
+                $(
+                    for &(x, y) in &[(7 as $t, 4 as $t), (-7, 4), (7, -4), (-7, -4), ($t::MAX, -1)] {
+                        let (wx, wy) = (Wrapping(x), Wrapping(y));
+                        assert_eq!(Euclid::div_euclid(&wx, &wy), Wrapping(Euclid::div_euclid(&x, &y)));
+                        assert_eq!(Euclid::rem_euclid(&wx, &wy), Wrapping(Euclid::rem_euclid(&x, &y)));
+                        let (q, r) = Euclid::div_rem_euclid(&x, &y);
+                        assert_eq!(Euclid::div_rem_euclid(&wx, &wy), (Wrapping(q), Wrapping(r)));
+                    }
+
+                    // The one overflowing case wraps, like `Wrapping(MIN) / Wrapping(-1)`.
+                    let (wx, wy) = (Wrapping($t::MIN), Wrapping(-1));
+                    assert_eq!(Euclid::div_euclid(&wx, &wy), Wrapping($t::MIN));
+                    assert_eq!(Euclid::rem_euclid(&wx, &wy), Wrapping(0));
+                    assert_eq!(Euclid::div_rem_euclid(&wx, &wy), (Wrapping($t::MIN), Wrapping(0)));
+                    assert_eq!(Euclid::div_euclid(&wx, &wy), wx / wy);
+                )+
+            };
+        }
+
+        test_euclid!(isize i8 i16 i32 i64 i128);
+
+        assert_eq!(
+            Euclid::div_euclid(&Wrapping(7u32), &Wrapping(4)),
+            Wrapping(1)
+        );
+        assert_eq!(
+            Euclid::rem_euclid(&Wrapping(7u32), &Wrapping(4)),
+            Wrapping(3)
+        );
+        assert_eq!(
+            Euclid::div_euclid(&Wrapping(-7i32), &Wrapping(4)),
+            Wrapping(-2)
+        );
+        assert_eq!(
+            Euclid::rem_euclid(&Wrapping(-7i32), &Wrapping(-4)),
+            Wrapping(1)
+        );
+    }
+
     #[test]
     fn euclid_checked() {
         macro_rules! test_euclid_checked {
@@ -276,4 +423,61 @@ mod tests {
 
         test_euclid_checked!(isize i8 i16 i32 i64 i128);
     }
+
+    #[test]
+    fn euclid_checked_float() {
+        macro_rules! test_euclid_checked {
+            ($($t:ident)+) => {
+                $(
+                    {
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&(-7.0 as $t), &4.0), Some(-2.0));
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&(-7.0 as $t), &4.0), Some(1.0));
+                        assert_eq!(CheckedEuclid::checked_div_rem_euclid(&(7.5 as $t), &-2.0), Some((-3.0, 1.5)));
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&(1.0 as $t), &0.0), None);
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&(1.0 as $t), &0.0), None);
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&(0.0 as $t), &0.0), None);
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&(0.0 as $t), &3.0), Some(0.0));
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&(0.0 as $t), &3.0), Some(0.0));
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&$t::INFINITY, &2.0), None);
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&$t::INFINITY, &2.0), None);
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&(2.0 as $t), &$t::INFINITY), Some(0.0));
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&(2.0 as $t), &$t::INFINITY), Some(2.0));
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&$t::MAX, &0.5), None);
+                        assert_eq!(CheckedEuclid::checked_div_euclid(&$t::NAN, &1.0), None);
+                        assert_eq!(CheckedEuclid::checked_rem_euclid(&(1.0 as $t), &$t::NAN), None);
+                    }
+                )+
+            };
+        }
+
+        test_euclid_checked!(f32 f64);
+    }
+
+    #[test]
+    fn rem_trunc() {
+        macro_rules! test_rem_trunc {
+            ($($t:ident)+) => {
+                $(
+                    {
+                        let x: $t = 7;
+                        let y: $t = 3;
+                        assert_eq!(RemTrunc::rem_trunc(&-x, &y), -1);
+                        assert_eq!(Euclid::rem_euclid(&-x, &y), 2);
+                        assert_eq!(RemTrunc::rem_trunc(&x, &-y), 1);
+                        assert_eq!(Euclid::rem_euclid(&x, &-y), 1);
+                        assert_eq!(RemTrunc::rem_trunc(&-x, &-y), -1);
+                        assert_eq!(RemTrunc::rem_trunc(&$t::min_value(), &-1), 0);
+                    }
+                )+
+            };
+        }
+
+        test_rem_trunc!(isize i8 i16 i32 i64 i128);
+
+        assert_eq!(RemTrunc::rem_trunc(&7u8, &3), 1);
+        assert_eq!(RemTrunc::rem_trunc(&-7.0f64, &3.0), -1.0);
+        assert_eq!(Euclid::rem_euclid(&-7.0f64, &3.0), 2.0);
+        assert_eq!(RemTrunc::rem_trunc(&-7.5f32, &-2.0), -1.5);
+        assert!(RemTrunc::rem_trunc(&1.0f64, &0.0).is_nan());
+    }
 }
@@ -1,3 +1,4 @@
+pub mod accumulate;
 pub mod bytes;
 pub mod checked;
 pub mod euclid;
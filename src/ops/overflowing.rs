@@ -1,3 +1,4 @@
+use core::num::Wrapping;
 use core::ops::{Add, Mul, Sub};
 use core::{i128, i16, i32, i64, i8, isize};
 use core::{u128, u16, u32, u64, u8, usize};
@@ -76,6 +77,34 @@ overflowing_impl!(OverflowingMul, overflowing_mul, i64);
 overflowing_impl!(OverflowingMul, overflowing_mul, isize);
 overflowing_impl!(OverflowingMul, overflowing_mul, i128);
 
+impl<T: OverflowingAdd> OverflowingAdd for Wrapping<T>
+where
+    Wrapping<T>: Add<Output = Wrapping<T>>,
+{
+    fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+        let (sum, overflow) = self.0.overflowing_add(&v.0);
+        (Wrapping(sum), overflow)
+    }
+}
+impl<T: OverflowingSub> OverflowingSub for Wrapping<T>
+where
+    Wrapping<T>: Sub<Output = Wrapping<T>>,
+{
+    fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+        let (difference, overflow) = self.0.overflowing_sub(&v.0);
+        (Wrapping(difference), overflow)
+    }
+}
+impl<T: OverflowingMul> OverflowingMul for Wrapping<T>
+where
+    Wrapping<T>: Mul<Output = Wrapping<T>>,
+{
+    fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+        let (product, overflow) = self.0.overflowing_mul(&v.0);
+        (Wrapping(product), overflow)
+    }
+}
+
 #[test]
 fn test_overflowing_traits() {
     fn overflowing_add<T: OverflowingAdd>(a: T, b: T) -> (T, bool) {
@@ -94,3 +123,41 @@ fn test_overflowing_traits() {
     assert_eq!(overflowing_mul(5i16, 2), (10, false));
     assert_eq!(overflowing_mul(1_000_000_000i32, 10), (1410065408, true));
 }
+
+#[test]
+fn overflowing_flag_matches_checked() {
+    use crate::ops::checked::{CheckedAdd, CheckedMul, CheckedSub};
+
+    macro_rules! test_flag {
+        ($($t:ident)+) => {$(
+            for &a in &[$t::MIN, $t::MIN + 1, 0, 1, 2, $t::MAX / 2 + 1, $t::MAX] {
+                for &b in &[$t::MIN, 0, 1, 2, $t::MAX] {
+                    assert_eq!(OverflowingAdd::overflowing_add(&a, &b).1, CheckedAdd::checked_add(&a, &b).is_none());
+                    assert_eq!(OverflowingSub::overflowing_sub(&a, &b).1, CheckedSub::checked_sub(&a, &b).is_none());
+                    assert_eq!(OverflowingMul::overflowing_mul(&a, &b).1, CheckedMul::checked_mul(&a, &b).is_none());
+                    assert_eq!(
+                        OverflowingAdd::overflowing_add(&Wrapping(a), &Wrapping(b)),
+                        (Wrapping(a) + Wrapping(b), OverflowingAdd::overflowing_add(&a, &b).1)
+                    );
+                    assert_eq!(
+                        OverflowingSub::overflowing_sub(&Wrapping(a), &Wrapping(b)),
+                        (Wrapping(a) - Wrapping(b), OverflowingSub::overflowing_sub(&a, &b).1)
+                    );
+                    assert_eq!(
+                        OverflowingMul::overflowing_mul(&Wrapping(a), &Wrapping(b)),
+                        (Wrapping(a) * Wrapping(b), OverflowingMul::overflowing_mul(&a, &b).1)
+                    );
+                }
+            }
+        )+};
+    }
+
+    test_flag!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+}
+
+#[test]
+fn wrapping_is_overflowing() {
+    fn require_overflowing<T: OverflowingAdd + OverflowingSub + OverflowingMul>(_: &T) {}
+    require_overflowing(&Wrapping(42u8));
+    require_overflowing(&Wrapping(-42i64));
+}
@@ -105,6 +105,35 @@ saturating_impl!(SaturatingMul, saturating_mul, i64);
 saturating_impl!(SaturatingMul, saturating_mul, isize);
 saturating_impl!(SaturatingMul, saturating_mul, i128);
 
+/// Performs negation of unsigned integers, saturating at zero.
+///
+/// The negation of any unsigned value is at most zero, so the result is always
+/// zero, instead of wrapping around as `wrapping_neg` does.
+pub trait UnsignedSaturatingNeg: Sized {
+    /// Saturating negation. Computes `-self`, saturating at zero.
+    ///
+    /// ```
+    /// use num_traits::ops::saturating::UnsignedSaturatingNeg;
+    ///
+    /// assert_eq!(UnsignedSaturatingNeg::saturating_neg(&5u8), 0);
+    /// assert_eq!(UnsignedSaturatingNeg::saturating_neg(&0u64), 0);
+    /// ```
+    fn saturating_neg(&self) -> Self;
+}
+
+macro_rules! unsigned_saturating_neg_impl {
+    ($($t:ty)*) => {$(
+        impl UnsignedSaturatingNeg for $t {
+            #[inline]
+            fn saturating_neg(&self) -> Self {
+                0
+            }
+        }
+    )*};
+}
+
+unsigned_saturating_neg_impl!(u8 u16 u32 u64 usize u128);
+
 // TODO: add SaturatingNeg for signed integer primitives once the saturating_neg() API is stable.
 
 #[test]
@@ -128,3 +157,17 @@ fn test_saturating_traits() {
     assert_eq!(saturating_mul(127, 2), 127i8);
     assert_eq!(saturating_mul(-128, 2), -128i8);
 }
+
+#[test]
+fn test_unsigned_saturating_neg() {
+    fn saturating_neg<T: UnsignedSaturatingNeg>(a: T) -> T {
+        a.saturating_neg()
+    }
+    assert_eq!(saturating_neg(5u8), 0);
+    assert_eq!(saturating_neg(0u8), 0);
+    assert_eq!(saturating_neg(u16::MAX), 0);
+    assert_eq!(saturating_neg(1u32), 0);
+    assert_eq!(saturating_neg(u64::MAX), 0);
+    assert_eq!(saturating_neg(usize::MAX), 0);
+    assert_eq!(saturating_neg(1u128 << 127), 0);
+}
@@ -1,4 +1,4 @@
-use crate::{CheckedMul, One};
+use crate::{CheckedMul, One, PrimInt};
 use core::num::Wrapping;
 use core::ops::Mul;
 
@@ -162,6 +162,66 @@ mod float_impls {
     pow_impl!(f64, f64, f64, <f64 as Float>::powf);
 }
 
+/// Raising a value to a power, returning `None` if the result overflows.
+pub trait CheckedPow<RHS> {
+    /// The result after applying the operator.
+    type Output;
+
+    /// Returns `self` to the power `rhs`, or `None` if the result overflows.
+    ///
+    /// Note that `0⁰` returns `Some(1)`, as for [`checked_pow`]. For
+    /// `Wrapping` types the result wraps around, so it is never `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::CheckedPow;
+    ///
+    /// assert_eq!(CheckedPow::checked_pow(10u8, 2u32), Some(100));
+    /// assert_eq!(CheckedPow::checked_pow(10u8, 3u32), None);
+    /// assert_eq!(CheckedPow::checked_pow(-2i64, 63usize), Some(i64::MIN));
+    /// ```
+    fn checked_pow(self, rhs: RHS) -> Option<Self::Output>;
+}
+
+macro_rules! checked_pow_impl {
+    ($($t:ty)+) => {$(
+        checked_pow_impl!($t, u8);
+        checked_pow_impl!($t, u16);
+        checked_pow_impl!($t, u32);
+
+        impl CheckedPow<usize> for $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: usize) -> Option<$t> {
+                checked_pow(self, rhs)
+            }
+        }
+    )+};
+    ($t:ty, $rhs:ty) => {
+        impl CheckedPow<$rhs> for $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: $rhs) -> Option<$t> {
+                <$t>::checked_pow(self, u32::from(rhs))
+            }
+        }
+    };
+}
+
+checked_pow_impl!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl<T, RHS> CheckedPow<RHS> for Wrapping<T>
+where
+    Wrapping<T>: Pow<RHS>,
+{
+    type Output = <Wrapping<T> as Pow<RHS>>::Output;
+    #[inline]
+    fn checked_pow(self, rhs: RHS) -> Option<Self::Output> {
+        Some(Pow::pow(self, rhs))
+    }
+}
+
 /// Raises a value to the power of exp, using exponentiation by squaring.
 ///
 /// Note that `0⁰` (`pow(0, 0)`) returns `1`. Mathematically this is undefined.
@@ -240,3 +300,163 @@ pub fn checked_pow<T: Clone + One + CheckedMul>(mut base: T, mut exp: usize) ->
     }
     Some(acc)
 }
+
+/// Returns `10^n` as `T`, or `None` if it overflows.
+///
+/// This is equivalent to `checked_pow(T::from(10), n)`, without the need to
+/// construct the base.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::pow10;
+///
+/// assert_eq!(pow10::<u32>(3), Some(1000));
+/// assert_eq!(pow10::<u8>(3), None);
+/// ```
+#[inline]
+pub fn pow10<T: PrimInt>(n: u32) -> Option<T> {
+    T::one().checked_pow10(n)
+}
+
+/// Returns `n!` as `T`, or `None` if it overflows.
+///
+/// Note that `0!` is `1`.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::checked_factorial;
+///
+/// assert_eq!(checked_factorial::<u32>(5), Some(120));
+/// assert_eq!(checked_factorial::<u8>(6), None); // 720 > 255
+/// ```
+#[inline]
+pub fn checked_factorial<T: PrimInt>(n: u32) -> Option<T> {
+    let mut acc = T::one();
+    for i in 2..=n {
+        acc = acc.checked_mul(&<T as crate::NumCast>::from(i)?)?;
+    }
+    Some(acc)
+}
+
+/// Returns the binomial coefficient `C(n, k)`, the number of ways to choose
+/// `k` items out of `n`, or `None` if it overflows.
+///
+/// The result is `0` unless `0 <= k <= n`. Intermediate products never exceed
+/// the final result, so `None` is returned only when the result itself
+/// doesn't fit in `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::checked_binomial;
+///
+/// assert_eq!(checked_binomial(5u32, 2), Some(10));
+/// assert_eq!(checked_binomial(60u64, 30), Some(118_264_581_564_861_424));
+/// assert_eq!(checked_binomial(10u8, 5), Some(252));
+/// assert_eq!(checked_binomial(11u8, 5), None);
+/// ```
+pub fn checked_binomial<T: PrimInt>(n: T, k: T) -> Option<T> {
+    if k < T::zero() || k > n {
+        return Some(T::zero());
+    }
+    let k = k.min(n - k);
+    let mut acc = T::one();
+    let mut i = T::one();
+    while i <= k {
+        // `acc * (n - k + i)` is divisible by `i`, so divide out their common
+        // factor first to keep the product no larger than the result.
+        let g = gcd(acc, i);
+        acc = (acc / g).checked_mul(&((n - k + i) / (i / g)))?;
+        i = i + T::one();
+    }
+    Some(acc)
+}
+
+fn gcd<T: PrimInt>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[test]
+fn checked_pow_trait() {
+    assert_eq!(CheckedPow::checked_pow(10u8, 3u32), None);
+    assert_eq!(CheckedPow::checked_pow(10u8, 2u32), Some(100));
+    assert_eq!(CheckedPow::checked_pow(10u8, 2u8), Some(100));
+    assert_eq!(CheckedPow::checked_pow(10u8, 2u16), Some(100));
+    assert_eq!(CheckedPow::checked_pow(10u8, 2usize), Some(100));
+    assert_eq!(CheckedPow::checked_pow(10u8, 3usize), None);
+    assert_eq!(CheckedPow::checked_pow(0u32, 0u32), Some(1));
+    assert_eq!(CheckedPow::checked_pow(-2i8, 7u32), Some(-128));
+    assert_eq!(CheckedPow::checked_pow(2i8, 7u32), None);
+    assert_eq!(CheckedPow::checked_pow(1u64, usize::MAX), Some(1));
+    assert_eq!(CheckedPow::checked_pow(3u128, 80u8), Some(3u128.pow(80)));
+    assert_eq!(CheckedPow::checked_pow(3u128, 81u8), None);
+
+    assert_eq!(
+        CheckedPow::checked_pow(Wrapping(10u8), 3u8),
+        Some(Wrapping(232))
+    );
+    assert_eq!(
+        CheckedPow::checked_pow(Wrapping(-3i32), 3usize),
+        Some(Wrapping(-27))
+    );
+
+    // Generic code can abstract over the exponent type.
+    fn cube<T: CheckedPow<E, Output = T>, E: From<u8>>(x: T) -> Option<T> {
+        x.checked_pow(E::from(3))
+    }
+    assert_eq!(cube::<i16, u32>(30), Some(27_000));
+    assert_eq!(cube::<i16, usize>(40), None);
+}
+
+#[test]
+fn checked_factorial_overflow() {
+    assert_eq!(checked_factorial::<u8>(0), Some(1));
+    assert_eq!(checked_factorial::<u8>(1), Some(1));
+    assert_eq!(checked_factorial::<u8>(5), Some(120));
+    assert_eq!(checked_factorial::<u8>(6), None);
+    assert_eq!(checked_factorial::<i8>(5), Some(120));
+    assert_eq!(checked_factorial::<i8>(6), None);
+    assert_eq!(checked_factorial::<u32>(5), Some(120));
+    assert_eq!(checked_factorial::<u32>(12), Some(479_001_600));
+    assert_eq!(checked_factorial::<u32>(13), None);
+    assert_eq!(
+        checked_factorial::<i64>(20),
+        Some(2_432_902_008_176_640_000)
+    );
+    assert_eq!(checked_factorial::<i64>(21), None);
+    assert_eq!(checked_factorial::<u128>(34).map(|f| f > 0), Some(true));
+    assert_eq!(checked_factorial::<u128>(35), None);
+    assert_eq!(checked_factorial::<u64>(u32::MAX), None);
+}
+
+#[test]
+fn checked_binomial_values() {
+    assert_eq!(checked_binomial(0u32, 0), Some(1));
+    assert_eq!(checked_binomial(5u32, 0), Some(1));
+    assert_eq!(checked_binomial(5u32, 5), Some(1));
+    assert_eq!(checked_binomial(5u32, 6), Some(0));
+    assert_eq!(checked_binomial(5i32, -1), Some(0));
+    assert_eq!(checked_binomial(-5i32, 2), Some(0));
+    assert_eq!(checked_binomial(52u32, 5), Some(2_598_960));
+    assert_eq!(checked_binomial(10u8, 5), Some(252));
+    assert_eq!(checked_binomial(11u8, 5), None);
+    assert_eq!(checked_binomial(255u8, 1), Some(255));
+    assert_eq!(checked_binomial(255u8, 254), Some(255));
+    assert_eq!(checked_binomial(255u8, 2), None);
+    assert_eq!(checked_binomial(127i8, 126), Some(127));
+    assert_eq!(checked_binomial(34u32, 17), Some(2_333_606_220));
+    assert_eq!(checked_binomial(35u32, 17), None);
+    assert_eq!(
+        checked_binomial(67u64, 33),
+        Some(14_226_520_737_620_288_370)
+    );
+    assert_eq!(checked_binomial(68u64, 34), None);
+    assert_eq!(checked_binomial(u128::MAX, 1), Some(u128::MAX));
+}
@@ -1,3 +1,4 @@
+use core::cmp::Ordering;
 use core::num::Wrapping;
 use core::ops::Neg;
 
@@ -191,6 +192,13 @@ pub fn signum<T: Signed>(value: T) -> T {
 }
 
 /// A trait for values which cannot be negative
+///
+/// The signed primitive types do not implement it:
+///
+/// ```compile_fail
+/// fn require_unsigned<T: num_traits::Unsigned>() {}
+/// require_unsigned::<i32>();
+/// ```
 pub trait Unsigned: Num {}
 
 macro_rules! empty_trait_impl {
@@ -203,6 +211,37 @@ empty_trait_impl!(Unsigned for usize u8 u16 u32 u64 u128);
 
 impl<T: Unsigned> Unsigned for Wrapping<T> where Wrapping<T>: Num {}
 
+/// Compares a number to zero.
+///
+/// This is implemented for every type that is `Num + PartialOrd`, including
+/// unsigned integers, and is often more convenient than `signum` for
+/// branching on the sign.
+pub trait SignCmp {
+    /// Returns `Less`, `Equal` or `Greater` as `self` is negative, zero or
+    /// positive.
+    ///
+    /// Both `+0.0` and `-0.0` are `Equal`. NaN is neither positive nor
+    /// negative, so it is also `Equal`; check `is_nan` first if that matters.
+    ///
+    /// ```
+    /// use num_traits::sign::SignCmp;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!((-5i32).sign(), Ordering::Less);
+    /// assert_eq!(0u8.sign(), Ordering::Equal);
+    /// assert_eq!((-0.0f64).sign(), Ordering::Equal);
+    /// assert_eq!(f32::INFINITY.sign(), Ordering::Greater);
+    /// ```
+    fn sign(&self) -> Ordering;
+}
+
+impl<T: Num + PartialOrd> SignCmp for T {
+    #[inline]
+    fn sign(&self) -> Ordering {
+        self.partial_cmp(&T::zero()).unwrap_or(Ordering::Equal)
+    }
+}
+
 #[test]
 fn unsigned_wrapping_is_unsigned() {
     fn require_unsigned<T: Unsigned>(_: &T) {}
@@ -214,3 +253,89 @@ fn signed_wrapping_is_signed() {
     fn require_signed<T: Signed>(_: &T) {}
     require_signed(&Wrapping(-42));
 }
+
+#[test]
+fn abs_sub_is_positive_difference() {
+    assert_eq!(abs_sub(3.0, 5.0), 0.0);
+    assert_eq!(abs_sub(5.0, 3.0), 2.0);
+    assert_eq!(abs_sub(-1.5f32, -4.0), 2.5);
+    assert_eq!(abs_sub(f64::INFINITY, 1.0), f64::INFINITY);
+    assert_eq!(abs_sub(1.0, f64::INFINITY), 0.0);
+    assert!(abs_sub(f64::NAN, 1.0).is_nan());
+    assert!(abs_sub(1.0f32, f32::NAN).is_nan());
+    assert!(abs_sub(f32::NAN, f32::NAN).is_nan());
+
+    assert_eq!(abs_sub(3, 5), 0);
+    assert_eq!(abs_sub(5i8, -3), 8);
+    assert_eq!(abs_sub(i64::MIN, 0), 0);
+    assert_eq!(abs_sub(Wrapping(7i16), Wrapping(2)), Wrapping(5));
+}
+
+#[test]
+fn sign_cmp() {
+    use core::cmp::Ordering::*;
+
+    assert_eq!((-3i8).sign(), Less);
+    assert_eq!(0i64.sign(), Equal);
+    assert_eq!(7isize.sign(), Greater);
+    assert_eq!(i128::MIN.sign(), Less);
+    assert_eq!(0u32.sign(), Equal);
+    assert_eq!(u128::MAX.sign(), Greater);
+    assert_eq!(Wrapping(-1i16).sign(), Less);
+
+    assert_eq!((-2.5f32).sign(), Less);
+    assert_eq!(0.0f64.sign(), Equal);
+    assert_eq!((-0.0f64).sign(), Equal);
+    assert_eq!(f64::MIN_POSITIVE.sign(), Greater);
+    assert_eq!(f32::NEG_INFINITY.sign(), Less);
+    assert_eq!(f64::NAN.sign(), Equal);
+    assert_eq!((-f32::NAN).sign(), Equal);
+}
+
+#[test]
+fn signed_generic() {
+    fn check<T: Signed + PartialOrd + Copy>(neg: T, pos: T) {
+        assert!(neg.is_negative() && !neg.is_positive());
+        assert!(pos.is_positive() && !pos.is_negative());
+        assert!(neg.abs() == pos);
+        assert!(neg.signum() == -T::one());
+        assert!(pos.signum() == T::one());
+        assert!(pos.abs_sub(&neg) == pos + pos);
+        assert!(neg.abs_sub(&pos).is_zero());
+    }
+
+    check(-3i32, 3);
+    check(-3i8, 3);
+    check(-0.5f32, 0.5);
+    check(-0.5f64, 0.5);
+    check(Wrapping(-3i64), Wrapping(3));
+
+    assert_eq!(Signed::signum(&-3), -1);
+    assert_eq!(Signed::signum(&0), 0);
+    assert!(!Signed::is_negative(&0i16) && !Signed::is_positive(&0i16));
+
+    assert_eq!(Signed::signum(&0.0f64), 1.0);
+    assert_eq!(Signed::signum(&-0.0f64), -1.0);
+    assert!(Signed::is_negative(&-0.0f64));
+    assert!(!Signed::is_positive(&-0.0f32));
+    assert!(Signed::signum(&f64::NAN).is_nan());
+}
+
+#[test]
+fn unsigned_types_are_unsigned() {
+    use crate::PrimInt;
+
+    fn require_unsigned<T: Unsigned>() {}
+    fn require_unsigned_int<T: Unsigned + PrimInt>() {}
+
+    require_unsigned::<u8>();
+    require_unsigned::<u16>();
+    require_unsigned::<u32>();
+    require_unsigned::<u64>();
+    require_unsigned::<u128>();
+    require_unsigned::<usize>();
+    require_unsigned::<Wrapping<u8>>();
+    require_unsigned::<Wrapping<u128>>();
+    require_unsigned_int::<u8>();
+    require_unsigned_int::<usize>();
+}
@@ -90,6 +90,269 @@ fn float_to_integer_checks_overflow() {
     assert_eq!(cast::<f64, i32>(source), None);
 }
 
+#[test]
+fn from_f64_exact() {
+    assert_eq!(i32::from_f64_exact(3.0), Some(3));
+    assert_eq!(i32::from_f64_exact(-3.0), Some(-3));
+    assert_eq!(i32::from_f64_exact(3.5), None);
+    assert_eq!(i32::from_f64_exact(-0.5), None);
+    assert_eq!(u32::from_f64_exact(-0.0), Some(0));
+    assert_eq!(u32::from_f64_exact(-1.0), None);
+    assert_eq!(i8::from_f64_exact(127.0), Some(127));
+    assert_eq!(i8::from_f64_exact(128.0), None);
+    assert_eq!(i8::from_f64_exact(-128.0), Some(-128));
+    assert_eq!(u64::from_f64_exact(9007199254740992.0), Some(1 << 53));
+    assert_eq!(i64::from_f64_exact(f64::NAN), None);
+    assert_eq!(i64::from_f64_exact(f64::INFINITY), None);
+    assert_eq!(f32::from_f64_exact(2.0), Some(2.0));
+    assert_eq!(f32::from_f64_exact(2.5), None);
+    assert_eq!(f32::from_f64_exact(16777216.0), Some(16777216.0));
+    assert_eq!(f32::from_f64_exact(16777217.0), None);
+    assert_eq!(f32::from_f64_exact(1e300), None);
+    assert_eq!(Wrapping::<u8>::from_f64_exact(42.0), Some(Wrapping(42)));
+}
+
+#[test]
+fn from_f64_saturating() {
+    assert_eq!(u8::from_f64_saturating(1e9), 255);
+    assert_eq!(u8::from_f64_saturating(f64::NAN), 0);
+    assert_eq!(u8::from_f64_saturating(-f64::NAN), 0);
+    assert_eq!(u8::from_f64_saturating(-1.0), 0);
+    assert_eq!(u8::from_f64_saturating(255.9), 255);
+    assert_eq!(i8::from_f64_saturating(-128.9), -128);
+    assert_eq!(i8::from_f64_saturating(-129.0), -128);
+    assert_eq!(i64::from_f64_saturating(f64::INFINITY), i64::MAX);
+    assert_eq!(i64::from_f64_saturating(f64::NEG_INFINITY), i64::MIN);
+    assert_eq!(u128::from_f64_saturating(f64::MAX), u128::MAX);
+    assert_eq!(i32::from_f64_saturating(-0.5), 0);
+    assert_eq!(f32::from_f64_saturating(1e300), f32::MAX);
+    assert_eq!(f32::from_f64_saturating(-1e300), f32::MIN);
+    assert_eq!(f32::from_f64_saturating(f64::INFINITY), f32::MAX);
+    assert_eq!(f32::from_f64_saturating(0.5), 0.5);
+    assert_eq!(
+        <Wrapping<u16>>::from_f64_saturating(1e6),
+        Wrapping(u16::MAX)
+    );
+}
+
+#[test]
+fn to_f64_round() {
+    use RoundMode::*;
+
+    let p53 = 1u64 << 53;
+    let below = 9007199254740992.0; // 2^53
+    let above = 9007199254740994.0; // 2^53 + 2
+
+    // Exactly representable values are unchanged in every mode.
+    for &mode in &[NearestEven, TowardZero, Up, Down] {
+        assert_eq!(p53.to_f64_round(mode), Some(below));
+        assert_eq!((p53 + 2).to_f64_round(mode), Some(above));
+        assert_eq!((-(p53 as i64)).to_f64_round(mode), Some(-below));
+        assert_eq!(0u8.to_f64_round(mode), Some(0.0));
+        assert_eq!(1.5f32.to_f64_round(mode), Some(1.5));
+    }
+
+    // 2^53 + 1 is a tie between 2^53 (even) and 2^53 + 2.
+    let x = p53 + 1;
+    assert_eq!(x.to_f64_round(NearestEven), Some(below));
+    assert_eq!(x.to_f64_round(TowardZero), Some(below));
+    assert_eq!(x.to_f64_round(Up), Some(above));
+    assert_eq!(x.to_f64_round(Down), Some(below));
+
+    // 2^53 + 3 is a tie between 2^53 + 2 and 2^53 + 4 (even).
+    let x = p53 + 3;
+    assert_eq!(x.to_f64_round(NearestEven), Some(9007199254740996.0));
+    assert_eq!(x.to_f64_round(TowardZero), Some(above));
+    assert_eq!(x.to_f64_round(Up), Some(9007199254740996.0));
+    assert_eq!(x.to_f64_round(Down), Some(above));
+
+    let x = -((p53 + 1) as i64);
+    assert_eq!(x.to_f64_round(NearestEven), Some(-below));
+    assert_eq!(x.to_f64_round(TowardZero), Some(-below));
+    assert_eq!(x.to_f64_round(Up), Some(-below));
+    assert_eq!(x.to_f64_round(Down), Some(-above));
+
+    // Not a tie: the nearest value is the upper one.
+    let x = u64::MAX;
+    let max = 18446744073709551616.0; // 2^64
+    assert_eq!(x.to_f64_round(NearestEven), Some(max));
+    assert_eq!(x.to_f64_round(Up), Some(max));
+    assert_eq!(x.to_f64_round(Down), Some(18446744073709549568.0));
+    assert_eq!(x.to_f64_round(TowardZero), Some(18446744073709549568.0));
+
+    // The extremes of 128-bit integers.
+    assert_eq!(i128::MIN.to_f64_round(Down), Some(i128::MIN as f64));
+    assert_eq!(i128::MAX.to_f64_round(Up), Some(i128::MAX as f64));
+    assert!(i128::MAX.to_f64_round(Down).unwrap() < i128::MAX as f64);
+    assert_eq!(u128::MAX.to_f64_round(Up), Some(u128::MAX as f64));
+    assert!(u128::MAX.to_f64_round(TowardZero).unwrap() < u128::MAX as f64);
+    assert_eq!(Wrapping(x).to_f64_round(Down), Some(18446744073709549568.0));
+}
+
+#[test]
+fn to_f64_round_default_with_fraction() {
+    use RoundMode::*;
+
+    // A type relying on the default methods, with a fractional part that its
+    // integer conversions truncate.
+    struct Real(f64);
+
+    impl ToPrimitive for Real {
+        fn to_i64(&self) -> Option<i64> {
+            self.0.to_i64()
+        }
+        fn to_u64(&self) -> Option<u64> {
+            self.0.to_u64()
+        }
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0)
+        }
+    }
+
+    for &mode in &[NearestEven, TowardZero, Up, Down] {
+        assert_eq!(Real(2.5).to_f64_round(mode), Some(2.5));
+        assert_eq!(Real(-0.75).to_f64_round(mode), Some(-0.75));
+        assert_eq!(Real(3.0).to_f64_round(mode), Some(3.0));
+        assert_eq!(Real(1e300).to_f64_round(mode), Some(1e300));
+        assert_eq!(Real(f64::INFINITY).to_f64_round(mode), Some(f64::INFINITY));
+        assert!(Real(f64::NAN).to_f64_round(mode).unwrap().is_nan());
+    }
+}
+
+#[test]
+fn try_into_nonzero() {
+    use core::num::*;
+
+    let x = NonZeroU32::new(300).unwrap();
+    assert_eq!(x.try_into_nonzero::<NonZeroU8>(), None);
+    assert_eq!(x.try_into_nonzero::<NonZeroU16>(), NonZeroU16::new(300));
+    assert_eq!(x.try_into_nonzero::<NonZeroI128>(), NonZeroI128::new(300));
+    assert_eq!(x.try_into_nonzero::<NonZeroU32>(), Some(x));
+
+    let y = NonZeroI64::new(-1).unwrap();
+    assert_eq!(y.try_into_nonzero::<NonZeroI8>(), NonZeroI8::new(-1));
+    assert_eq!(y.try_into_nonzero::<NonZeroU64>(), None);
+    assert_eq!(y.try_into_nonzero::<NonZeroUsize>(), None);
+
+    let z = NonZeroU128::new(u128::MAX).unwrap();
+    assert_eq!(z.try_into_nonzero::<NonZeroI128>(), None);
+    assert_eq!(z.try_into_nonzero::<NonZeroIsize>(), None);
+    assert_eq!(
+        NonZeroI8::new(i8::MIN)
+            .unwrap()
+            .try_into_nonzero::<NonZeroI16>(),
+        NonZeroI16::new(-128)
+    );
+}
+
+#[test]
+fn as_primitive_nonzero() {
+    use core::num::*;
+
+    assert_eq!(AsPrimitive::<f64>::as_(NonZeroU8::new(255).unwrap()), 255.0);
+    assert_eq!(AsPrimitive::<char>::as_(NonZeroU8::new(65).unwrap()), 'A');
+    assert_eq!(AsPrimitive::<i8>::as_(NonZeroU8::new(255).unwrap()), -1);
+    assert_eq!(
+        AsPrimitive::<u32>::as_(NonZeroI8::new(-1).unwrap()),
+        u32::MAX
+    );
+    assert_eq!(AsPrimitive::<u8>::as_(NonZeroU32::new(300).unwrap()), 44);
+    assert_eq!(
+        AsPrimitive::<f32>::as_(NonZeroUsize::new(42).unwrap()),
+        42.0
+    );
+    assert_eq!(
+        AsPrimitive::<i64>::as_(NonZeroI128::new(i128::MIN).unwrap()),
+        0
+    );
+    assert_eq!(
+        AsPrimitive::<u128>::as_(NonZeroU64::new(u64::MAX).unwrap()),
+        u64::MAX as u128
+    );
+    assert_eq!(
+        AsPrimitive::<f64>::as_(NonZeroIsize::new(-7).unwrap()),
+        -7.0
+    );
+
+    fn to_f64<T: AsPrimitive<f64>>(x: T) -> f64 {
+        x.as_()
+    }
+    assert_eq!(to_f64(NonZeroU16::new(1000).unwrap()), 1000.0);
+    assert_eq!(to_f64(NonZeroI32::new(-1000).unwrap()), -1000.0);
+}
+
+#[test]
+fn cast_exact() {
+    assert_eq!((1u64 << 40).cast_exact::<f32>(), Some(1099511627776.0));
+    assert_eq!(((1u64 << 40) + 1).cast_exact::<f32>(), None);
+    assert_eq!(16777216u32.cast_exact::<f32>(), Some(16777216.0));
+    assert_eq!(16777217u32.cast_exact::<f32>(), None);
+    assert_eq!(u64::MAX.cast_exact::<f64>(), None);
+    assert_eq!(i64::MIN.cast_exact::<f64>(), Some(-9223372036854775808.0));
+    assert_eq!(100u8.cast_exact::<i8>(), Some(100));
+    assert_eq!(200u8.cast_exact::<i8>(), None);
+    assert_eq!((-1i32).cast_exact::<u32>(), None);
+
+    assert_eq!(0.5f64.cast_exact::<f32>(), Some(0.5));
+    assert_eq!(0.1f64.cast_exact::<f32>(), None);
+    assert_eq!(1e300f64.cast_exact::<f32>(), None);
+    assert_eq!(f64::INFINITY.cast_exact::<f32>(), Some(f32::INFINITY));
+    assert_eq!(f64::NAN.cast_exact::<f32>(), None);
+    assert_eq!((-42.0f32).cast_exact::<i8>(), Some(-42));
+    assert_eq!((-42.5f32).cast_exact::<i8>(), None);
+    assert_eq!(
+        Wrapping(7u16).cast_exact::<Wrapping<u8>>(),
+        Some(Wrapping(7))
+    );
+
+    assert_eq!(num_traits::cast::cast_exact::<i32, u8>(255), Some(255));
+    assert_eq!(num_traits::cast::cast_exact::<i32, u8>(256), None);
+}
+
+#[test]
+fn cast_is_lossless() {
+    assert!(0u64.cast_is_lossless::<f32>());
+    assert!(12345u64.cast_is_lossless::<f32>());
+    assert!((1u64 << 63).cast_is_lossless::<f32>());
+    assert!(!u64::MAX.cast_is_lossless::<f32>());
+    assert!(!u64::MAX.cast_is_lossless::<f64>());
+    assert!(u64::MAX.cast_is_lossless::<u128>());
+    assert!(!u64::MAX.cast_is_lossless::<i64>());
+    assert!(i32::MIN.cast_is_lossless::<f64>());
+    assert!(!i32::MAX.cast_is_lossless::<f32>());
+
+    assert!(255.0f32.cast_is_lossless::<u8>());
+    assert!(!256.0f32.cast_is_lossless::<u8>());
+    assert!(!0.25f64.cast_is_lossless::<i64>());
+    assert!(0.25f64.cast_is_lossless::<f32>());
+    assert!(!f32::NAN.cast_is_lossless::<f64>());
+}
+
+#[test]
+fn try_cast() {
+    use num_traits::cast::{try_cast, CastError::*};
+
+    assert_eq!(try_cast::<f64, i32>(0.0), Ok(0));
+    assert_eq!(try_cast::<f64, i32>(-0.9), Ok(0));
+    assert_eq!(try_cast::<f64, i32>(2147483647.9), Ok(i32::MAX));
+    assert_eq!(try_cast::<f64, i32>(-2147483648.9), Ok(i32::MIN));
+    assert_eq!(try_cast::<f64, i32>(2147483648.0), Err(Overflow));
+    assert_eq!(try_cast::<f64, i32>(1e300), Err(Overflow));
+    assert_eq!(try_cast::<f64, i32>(-2147483649.0), Err(Underflow));
+    assert_eq!(try_cast::<f64, i32>(-1e300), Err(Underflow));
+    assert_eq!(try_cast::<f64, i32>(f64::NAN), Err(NotFinite));
+    assert_eq!(try_cast::<f64, i32>(f64::INFINITY), Err(NotFinite));
+    assert_eq!(try_cast::<f64, i32>(f64::NEG_INFINITY), Err(NotFinite));
+
+    assert_eq!(try_cast::<i32, u8>(-1), Err(Underflow));
+    assert_eq!(try_cast::<i32, u8>(256), Err(Overflow));
+    assert_eq!(try_cast::<u128, i64>(u128::MAX), Err(Overflow));
+    assert_eq!(try_cast::<i128, u64>(i128::MIN), Err(Underflow));
+    assert_eq!(try_cast::<f64, f32>(1e300), Ok(f32::INFINITY));
+    assert_eq!(i16::try_cast_from(Wrapping(40000u32)), Err(Overflow));
+    assert_eq!(Wrapping::<u8>::try_cast_from(-3i8), Err(Underflow));
+}
+
 #[test]
 fn cast_to_int_checks_overflow() {
     let big_f: f64 = 1.0e123;
@@ -239,6 +502,63 @@ fn cast_float_to_i128_edge_cases() {
     float_test_edge!(f64 -> i128 u128);
 }
 
+macro_rules! float_test_saturating {
+    ($f:ident -> $($t:ident)+) => { $({
+        dbg!("testing saturating cast for {} -> {}", stringify!($f), stringify!($t));
+
+        let signed = $t::MIN != 0;
+        let bits = mem::size_of::<$t>() as i32 * 8 - signed as i32;
+        let digits = $f::MANTISSA_DIGITS as i32;
+
+        // `limit` is `MAX + 1`, the first value that is out of range. It is
+        // infinite for `f32 -> u128`, and `MAX as $f` rounds up to it whenever
+        // `MAX` has more significant bits than the float's mantissa.
+        let limit = (2.0 as $f).powi(bits);
+        let below = limit.raw_dec();
+        assert_eq!(Some(below as $t), cast::<$f, $t>(below));
+        assert_eq!(None, cast::<$f, $t>(limit));
+        assert_eq!(None, cast::<$f, $t>(limit.raw_inc()));
+        assert_eq!($t::MAX, limit.as_());
+        assert_eq!($t::MAX, ($t::MAX as $f).as_());
+        assert_eq!(bits < digits, cast::<$f, $t>($t::MAX as $f).is_some());
+
+        // The minimum is exactly representable, and the next float down still
+        // truncates to it unless the spacing there is at least 1.
+        let min = $t::MIN as $f;
+        let next = if signed { min.raw_inc() } else { -min.raw_inc() };
+        assert_eq!(Some($t::MIN), cast::<$f, $t>(min));
+        assert_eq!($t::MIN, min.as_());
+        assert_eq!($t::MIN, next.as_());
+        if bits < digits - 1 || !signed {
+            assert_eq!(Some($t::MIN), cast::<$f, $t>(next));
+        } else {
+            assert_eq!(None, cast::<$f, $t>(next));
+        }
+
+        assert_eq!(0 as $t, $f::NAN.as_());
+        assert_eq!($t::MAX, $f::INFINITY.as_());
+        assert_eq!($t::MIN, $f::NEG_INFINITY.as_());
+        if limit.is_finite() {
+            assert_eq!($t::MAX, $f::MAX.as_());
+        }
+        assert_eq!($t::MIN, $f::MIN.as_());
+
+        // `from_f64_saturating` must agree with the saturating `as` cast.
+        for &x in &[limit, below, min, next, $f::NAN, $f::INFINITY, $f::NEG_INFINITY, $f::MIN] {
+            let x = x as f64;
+            assert_eq!(<$t as AsPrimitive<$t>>::as_(x.as_()), $t::from_f64_saturating(x));
+        }
+    })+}
+}
+
+#[test]
+fn cast_float_to_int_saturating() {
+    float_test_saturating!(f32 -> isize i8 i16 i32 i64 i128);
+    float_test_saturating!(f32 -> usize u8 u16 u32 u64 u128);
+    float_test_saturating!(f64 -> isize i8 i16 i32 i64 i128);
+    float_test_saturating!(f64 -> usize u8 u16 u32 u64 u128);
+}
+
 macro_rules! int_test_edge {
     ($f:ident -> { $($t:ident)+ } with $BigS:ident $BigU:ident ) => { $({
         #[allow(arithmetic_overflow)] // https://github.com/rust-lang/rust/issues/109731
@@ -310,6 +630,35 @@ fn cast_int_to_128_edge_cases() {
     test_edge!(usize u8 u16 u32 u64 u128);
 }
 
+#[test]
+fn round_trip_128_bit_extremes() {
+    assert_eq!(u128::MAX.to_u128(), Some(u128::MAX));
+    assert_eq!(u128::from_u128(u128::MAX), Some(u128::MAX));
+    assert_eq!(<u128 as NumCast>::from(u128::MAX), Some(u128::MAX));
+    assert_eq!(cast::<u128, u128>(u128::MAX), Some(u128::MAX));
+    assert_eq!(u128::MAX.to_i128(), None);
+    assert_eq!(u128::MAX.to_u64(), None);
+    assert_eq!(i128::from_u128(u128::MAX), None);
+    assert_eq!(
+        Wrapping::<u128>::from_u128(u128::MAX),
+        Some(Wrapping(u128::MAX))
+    );
+    assert_eq!(Wrapping(u128::MAX).to_u128(), Some(u128::MAX));
+
+    assert_eq!(i128::MIN.to_i128(), Some(i128::MIN));
+    assert_eq!(i128::from_i128(i128::MIN), Some(i128::MIN));
+    assert_eq!(cast::<i128, i128>(i128::MIN), Some(i128::MIN));
+    assert_eq!(cast::<i128, u128>(i128::MAX), Some(i128::MAX as u128));
+    assert_eq!(cast::<u128, i128>(i128::MAX as u128), Some(i128::MAX));
+    assert_eq!(cast::<i128, u128>(i128::MIN), None);
+
+    // Values beyond 64 bits go through the 128-bit paths exactly.
+    let big = (1u128 << 100) + 1;
+    assert_eq!(cast::<u128, i128>(big), Some(big as i128));
+    assert_eq!(u128::from_f64(2f64.powi(127)), Some(1 << 127));
+    assert_eq!((1u128 << 127).to_f64(), Some(2f64.powi(127)));
+}
+
 #[test]
 fn newtype_from_primitive() {
     #[derive(PartialEq, Debug)]